@@ -3,12 +3,30 @@ use bevy_asset_loader::asset_collection::AssetCollection;
 use bevy_enoki::Particle2dEffect;
 use bevy_seedling::sample::Sample;
 
+use crate::combat::AttackDefinition;
+
+#[derive(AssetCollection, Resource)]
+pub(super) struct AttackAssets {
+    #[asset(path = "attacks/bite.attack.ron")]
+    pub(super) bite: Handle<AttackDefinition>,
+    #[asset(path = "attacks/dash.attack.ron")]
+    pub(super) dash: Handle<AttackDefinition>,
+    #[asset(path = "attacks/parry.attack.ron")]
+    pub(super) parry: Handle<AttackDefinition>,
+    #[asset(path = "attacks/potion_throw.attack.ron")]
+    pub(super) potion_throw: Handle<AttackDefinition>,
+    #[asset(path = "attacks/spit.attack.ron")]
+    pub(super) spit: Handle<AttackDefinition>,
+    #[asset(path = "attacks/staff_slam.attack.ron")]
+    pub(super) staff_slam: Handle<AttackDefinition>,
+    #[asset(path = "attacks/staff_thrust.attack.ron")]
+    pub(super) staff_thrust: Handle<AttackDefinition>,
+}
+
 #[derive(AssetCollection, Resource)]
 pub(super) struct SpriteAssets {
     #[asset(path = "sprites/background.png")]
     pub(super) background: Handle<Image>,
-    #[asset(path = "sprites/bite.png")]
-    pub(super) bite: Handle<Image>,
     #[asset(path = "sprites/enemy.png")]
     pub(super) enemy: Handle<Image>,
     #[asset(path = "sprites/knob.png")]
@@ -17,32 +35,36 @@ pub(super) struct SpriteAssets {
     pub(super) outline: Handle<Image>,
     #[asset(path = "sprites/player.png")]
     pub(super) player: Handle<Image>,
-    #[asset(path = "sprites/potion.png")]
-    pub(super) potion: Handle<Image>,
     #[asset(path = "sprites/staff.png")]
     pub(super) staff: Handle<Image>,
 }
 
 #[derive(AssetCollection, Resource)]
 pub(super) struct ParticleAssets {
+    #[asset(path = "effects/acid_puddle.ron")]
+    pub(super) acid_puddle: Handle<Particle2dEffect>,
     #[asset(path = "effects/apply_mark.ron")]
     pub(super) apply_mark: Handle<Particle2dEffect>,
+    #[asset(path = "effects/fire_patch.ron")]
+    pub(super) fire_patch: Handle<Particle2dEffect>,
+    #[asset(path = "effects/frost_mark.ron")]
+    pub(super) frost_mark: Handle<Particle2dEffect>,
+    #[asset(path = "effects/leech_mark.ron")]
+    pub(super) leech_mark: Handle<Particle2dEffect>,
     #[asset(path = "effects/mark.ron")]
     pub(super) mark: Handle<Particle2dEffect>,
+    #[asset(path = "effects/potion_shatter.ron")]
+    pub(super) potion_shatter: Handle<Particle2dEffect>,
+    #[asset(path = "effects/tar_cloud.ron")]
+    pub(super) tar_cloud: Handle<Particle2dEffect>,
     #[asset(path = "effects/trigger.ron")]
     pub(super) trigger: Handle<Particle2dEffect>,
+    #[asset(path = "effects/volatile_mark.ron")]
+    pub(super) volatile_mark: Handle<Particle2dEffect>,
 }
 
 #[derive(AssetCollection, Resource)]
 pub(super) struct AudioAssets {
-    #[asset(path = "audio/bite_impact.ogg")]
-    pub(super) bite_impact: Handle<Sample>,
-    #[asset(path = "audio/bite_swing.ogg")]
-    pub(super) bite_swing: Handle<Sample>,
     #[asset(path = "audio/mark_triggered.ogg")]
     pub(super) mark_triggered: Handle<Sample>,
-    #[asset(path = "audio/staff_impact.ogg")]
-    pub(super) staff_impact: Handle<Sample>,
-    #[asset(path = "audio/staff_swing.ogg")]
-    pub(super) staff_swing: Handle<Sample>,
 }
@@ -2,6 +2,8 @@ use avian2d::prelude::OnCollisionStart;
 use bevy::prelude::*;
 use bevy_seedling::sample::{Sample, SamplePlayer};
 
+use crate::combat::Landed;
+
 #[derive(Component, Reflect, DerefMut, Deref)]
 pub(super) struct HitboxSound(pub(super) Handle<Sample>);
 
@@ -14,3 +16,13 @@ pub(super) fn spawn_collision_sound(
         commands.spawn(SamplePlayer::new(sound.0.clone_weak()));
     }
 }
+
+pub(super) fn spawn_landing_sound(
+    trigger: Trigger<Landed>,
+    mut commands: Commands,
+    sound_q: Query<&HitboxSound>,
+) {
+    if let Ok(sound) = sound_q.get(trigger.target()) {
+        commands.spawn(SamplePlayer::new(sound.0.clone_weak()));
+    }
+}
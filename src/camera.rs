@@ -5,7 +5,19 @@ use bevy_enhanced_input::prelude::*;
 #[cfg(debug_assertions)]
 use bevy_inspector_egui::bevy_egui::EguiContexts;
 
-use crate::{AssetState, GameState, InGame, player::Player};
+use crate::{
+    AssetState, GameState, InGame,
+    combat::Damaged,
+    player::{CyclePotion, Player},
+    settings::Settings,
+};
+
+const TRAUMA_PER_DAMAGE: f32 = 0.02;
+
+const TRAUMA_DECAY: f32 = 1.5;
+
+const MAX_SHAKE_OFFSET: f32 = 6.;
+const MAX_SHAKE_ANGLE: f32 = 0.05;
 
 pub(super) struct CameraPlugin;
 
@@ -14,10 +26,24 @@ impl Plugin for CameraPlugin {
         app.add_observer(zoom)
             .add_observer(binding)
             .add_systems(OnEnter(AssetState::Loaded), startup)
-            .add_systems(Update, update_camera.run_if(in_state(GameState::Running)));
+            .add_systems(
+                Update,
+                (add_trauma, update_camera, shake_camera)
+                    .chain()
+                    .run_if(in_state(GameState::Running)),
+            );
+
+        #[cfg(debug_assertions)]
+        app.register_type::<Shake>();
     }
 }
 
+#[derive(Component, Reflect, Default)]
+struct Shake {
+    trauma: f32,
+    offset: Vec2,
+}
+
 #[derive(Debug, InputAction)]
 #[input_action(output = Vec2)]
 struct Zoom;
@@ -25,7 +51,7 @@ struct Zoom;
 fn startup(mut commands: Commands) {
     let mut proj = OrthographicProjection::default_2d();
     proj.scale = 0.2;
-    commands.spawn((Camera2d, Projection::Orthographic(proj)));
+    commands.spawn((Camera2d, Projection::Orthographic(proj), Shake::default()));
 }
 
 #[cfg(debug_assertions)]
@@ -57,7 +83,43 @@ fn update_camera(
         .smooth_nudge(&direction, 2., time.delta_secs());
 }
 
+fn add_trauma(
+    mut damaged: EventReader<Damaged>,
+    mut shake: Single<&mut Shake>,
+    player: Single<Entity, With<Player>>,
+    settings: Res<Settings>,
+) {
+    for event in damaged.read() {
+        if !settings.screen_shake || (event.source != *player && event.target != *player) {
+            continue;
+        }
+
+        shake.trauma = (shake.trauma
+            + f32::from(event.amount) * TRAUMA_PER_DAMAGE * settings.screen_shake_intensity)
+            .clamp(0., 1.);
+    }
+}
+
+fn shake_camera(camera: Single<(&mut Transform, &mut Shake)>, time: Res<Time<Real>>) {
+    let (mut transform, mut shake) = camera.into_inner();
+
+    transform.translation -= shake.offset.extend(0.);
+    shake.trauma = (shake.trauma - TRAUMA_DECAY * time.delta_secs()).max(0.);
+
+    // Sines of unrelated frequencies stand in for noise, they are smooth and never line up.
+    let strength = shake.trauma * shake.trauma;
+    let t = time.elapsed_secs();
+    shake.offset = Vec2::new((t * 47.).sin(), (t * 53. + 1.).sin()) * MAX_SHAKE_OFFSET * strength;
+
+    transform.translation += shake.offset.extend(0.);
+    transform.rotation = Quat::from_rotation_z((t * 41. + 2.).sin() * MAX_SHAKE_ANGLE * strength);
+}
+
 fn binding(trigger: Trigger<Binding<InGame>>, mut players: Query<&mut Actions<InGame>>) {
     let mut actions = players.get_mut(trigger.target()).unwrap();
-    actions.bind::<Zoom>().to(Input::mouse_wheel());
+    // Shift + wheel cycles the potion belt instead.
+    actions
+        .bind::<Zoom>()
+        .to(Input::mouse_wheel())
+        .with_conditions(BlockBy::<CyclePotion>::default());
 }
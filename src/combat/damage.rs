@@ -0,0 +1,302 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, time::Stopwatch};
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::combat::Health;
+use crate::status::StatusEffects;
+
+#[derive(Event, Clone, Copy)]
+pub(crate) struct DamageEvent {
+    pub(crate) target: Entity,
+    pub(crate) source: Entity,
+    pub(crate) amount: i16,
+    pub(crate) damage_type: DamageType,
+    pub(crate) kind: DamageKind,
+    pub(crate) position: Vec2,
+}
+
+#[derive(Event, Clone, Copy)]
+pub(crate) struct Damaged {
+    pub(crate) target: Entity,
+    pub(crate) source: Entity,
+    pub(crate) amount: i16,
+    pub(crate) damage_type: DamageType,
+    pub(crate) kind: DamageKind,
+    pub(crate) position: Vec2,
+    pub(crate) critical: bool,
+}
+
+#[derive(Event, Clone, Copy)]
+pub(crate) struct Killed {
+    pub(crate) target: Entity,
+    pub(crate) source: Entity,
+    pub(crate) position: Vec2,
+}
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum DamageKind {
+    Hazard,
+    Hit,
+    MarkChain,
+    MarkDetonation,
+    Status,
+}
+
+#[derive(Reflect, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub(crate) enum DamageType {
+    Acid,
+    Arcane,
+    Fire,
+    Frost,
+    #[default]
+    Physical,
+}
+
+#[derive(Component, Reflect)]
+pub(crate) struct Resistances {
+    pub(crate) acid: f32,
+    pub(crate) arcane: f32,
+    pub(crate) fire: f32,
+    pub(crate) frost: f32,
+    pub(crate) physical: f32,
+}
+
+#[derive(Component, Reflect)]
+pub(crate) struct DamageModifiers {
+    pub(crate) dealt: f32,
+    pub(crate) taken: f32,
+    pub(crate) critical_chance: f32,
+    pub(crate) critical_multiplier: f32,
+}
+
+#[derive(Component, Reflect)]
+pub(crate) struct Empowered {
+    pub(crate) multiplier: f32,
+    pub(crate) timer: Timer,
+}
+
+#[derive(Component, Reflect)]
+pub(crate) struct InvulnerableOnHit(pub(crate) Duration);
+
+#[derive(Component, Reflect)]
+pub(crate) struct Invulnerable {
+    pub(crate) duration: Duration,
+    pub(crate) stopwatch: Stopwatch,
+}
+
+impl Invulnerable {
+    pub(crate) fn grant(
+        entity_commands: &mut EntityCommands,
+        current: Option<&Self>,
+        duration: Duration,
+    ) {
+        if current.is_some_and(|current| {
+            current.duration.saturating_sub(current.stopwatch.elapsed()) >= duration
+        }) {
+            return;
+        }
+
+        entity_commands.insert(Self {
+            duration,
+            stopwatch: Stopwatch::new(),
+        });
+    }
+}
+
+impl Resistances {
+    pub(crate) fn multiplier(&self, damage_type: DamageType) -> f32 {
+        match damage_type {
+            DamageType::Acid => self.acid,
+            DamageType::Arcane => self.arcane,
+            DamageType::Fire => self.fire,
+            DamageType::Frost => self.frost,
+            DamageType::Physical => self.physical,
+        }
+    }
+}
+
+impl Default for Resistances {
+    fn default() -> Self {
+        Self {
+            acid: 1.,
+            arcane: 1.,
+            fire: 1.,
+            frost: 1.,
+            physical: 1.,
+        }
+    }
+}
+
+impl Default for DamageModifiers {
+    fn default() -> Self {
+        Self {
+            dealt: 1.,
+            taken: 1.,
+            critical_chance: 0.,
+            critical_multiplier: 1.5,
+        }
+    }
+}
+
+fn scale_damage(amount: i16, multiplier: f32) -> i16 {
+    (f32::from(amount) * multiplier).round().max(0.) as i16
+}
+
+pub(crate) fn apply_damage(
+    mut damage_events: EventReader<DamageEvent>,
+    mut damaged: EventWriter<Damaged>,
+    mut killed: EventWriter<Killed>,
+    mut health_q: Query<&mut Health>,
+    modifiers_q: Query<&DamageModifiers>,
+    empowered_q: Query<&Empowered>,
+    status_q: Query<&StatusEffects>,
+    resistances_q: Query<&Resistances>,
+) {
+    let mut rng = rand::rng();
+
+    for event in damage_events.read() {
+        let Ok(mut health) = health_q.get_mut(event.target) else {
+            continue;
+        };
+
+        // Already dead, but not despawned yet.
+        if health.current <= 0 {
+            continue;
+        }
+
+        let (dealt, critical) = match modifiers_q.get(event.source) {
+            Ok(modifiers)
+                if rng.random_bool(f64::from(modifiers.critical_chance.clamp(0., 1.))) =>
+            {
+                (modifiers.dealt * modifiers.critical_multiplier, true)
+            }
+            Ok(modifiers) => (modifiers.dealt, false),
+            Err(_) => (1., false),
+        };
+        let dealt = dealt
+            * empowered_q
+                .get(event.source)
+                .map_or(1., |empowered| empowered.multiplier)
+            * status_q
+                .get(event.source)
+                .map_or(1., StatusEffects::damage_multiplier);
+        let taken = modifiers_q
+            .get(event.target)
+            .map_or(1., |modifiers| modifiers.taken);
+        let resisted = resistances_q
+            .get(event.target)
+            .map_or(1., |resistances| resistances.multiplier(event.damage_type));
+
+        let amount = scale_damage(event.amount, dealt * taken * resisted);
+        health.current = (health.current - amount).clamp(0, health.max);
+
+        damaged.write(Damaged {
+            target: event.target,
+            source: event.source,
+            amount,
+            damage_type: event.damage_type,
+            kind: event.kind,
+            position: event.position,
+            critical,
+        });
+
+        if health.current == 0 {
+            killed.write(Killed {
+                target: event.target,
+                source: event.source,
+                position: event.position,
+            });
+        }
+    }
+}
+
+pub(crate) fn tick_empowered(
+    empowered_q: Query<(Entity, &mut Empowered)>,
+    mut commands: Commands,
+    time: Res<Time<Virtual>>,
+) {
+    for (entity, mut empowered) in empowered_q {
+        if empowered.timer.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Empowered>();
+        }
+    }
+}
+
+pub(crate) fn tick_invulnerable(
+    invulnerable_q: Query<(Entity, &mut Invulnerable, Option<&mut Sprite>)>,
+    mut commands: Commands,
+    time: Res<Time<Virtual>>,
+) {
+    const BLINK_INTERVAL: f32 = 0.1;
+
+    let delta = time.delta();
+    for (entity, mut invulnerable, sprite) in invulnerable_q {
+        invulnerable.stopwatch.tick(delta);
+
+        let finished = invulnerable.stopwatch.elapsed() >= invulnerable.duration;
+
+        if let Some(mut sprite) = sprite {
+            let blinking = (invulnerable.stopwatch.elapsed_secs() / BLINK_INTERVAL) as u32 % 2 == 0;
+            let alpha = if blinking && !finished { 0.25 } else { 1. };
+            sprite.color.set_alpha(alpha);
+        }
+
+        if finished {
+            commands.entity(entity).remove::<Invulnerable>();
+        }
+    }
+}
+
+pub(crate) fn despawn_killed(mut commands: Commands, mut killed: EventReader<Killed>) {
+    for event in killed.read() {
+        commands.entity(event.target).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_damage_rounds_to_nearest() {
+        assert_eq!(scale_damage(10, 1.), 10);
+        assert_eq!(scale_damage(10, 1.25), 13);
+        assert_eq!(scale_damage(3, 0.5), 2);
+    }
+
+    #[test]
+    fn scale_damage_never_heals() {
+        assert_eq!(scale_damage(10, -2.), 0);
+        assert_eq!(scale_damage(10, 0.), 0);
+    }
+
+    #[test]
+    fn resistances_default_to_full_damage() {
+        let resistances = Resistances::default();
+
+        for damage_type in [
+            DamageType::Acid,
+            DamageType::Arcane,
+            DamageType::Fire,
+            DamageType::Frost,
+            DamageType::Physical,
+        ] {
+            assert_eq!(resistances.multiplier(damage_type), 1.);
+        }
+    }
+
+    #[test]
+    fn resistances_pick_the_matching_type() {
+        let resistances = Resistances {
+            fire: 0.5,
+            frost: 2.,
+            ..default()
+        };
+
+        assert_eq!(resistances.multiplier(DamageType::Fire), 0.5);
+        assert_eq!(resistances.multiplier(DamageType::Frost), 2.);
+        assert_eq!(resistances.multiplier(DamageType::Acid), 1.);
+    }
+}
@@ -0,0 +1,805 @@
+use std::{error::Error, fmt, time::Duration};
+
+use avian2d::prelude::{AngularVelocity, Collider, LinearVelocity};
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+    time::Stopwatch,
+};
+use bevy_seedling::{
+    prelude::Volume,
+    sample::{Sample, SamplePlayer},
+};
+use serde::Deserialize;
+
+use crate::{
+    AttackMarker,
+    combat::{AttackMovement, AttackMovements, Combo, DamageType, Swing, Swings},
+    movement::Rooted,
+};
+
+#[derive(Asset, TypePath, Deserialize)]
+pub(crate) struct AttackDefinition {
+    #[serde(default)]
+    pub(crate) cancels_recovery: bool,
+    #[serde(default)]
+    pub(crate) charge: Option<ChargeDefinition>,
+    #[serde(default)]
+    pub(crate) combo: Option<ComboDefinition>,
+    #[serde(default)]
+    pub(crate) cooldown: f32,
+    #[serde(default)]
+    pub(crate) cost: f32,
+    pub(crate) rooted: f32,
+    pub(crate) timeline: Vec<Keyframe>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ChargeDefinition {
+    #[serde(default = "unchanged")]
+    damage: f32,
+    #[serde(default = "unchanged")]
+    range: f32,
+    #[serde(default = "unchanged")]
+    size: f32,
+    time: f32,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct ChargeScale {
+    pub(crate) damage: f32,
+    pub(crate) range: f32,
+    pub(crate) size: f32,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ComboDefinition {
+    #[serde(skip)]
+    pub(crate) handle: Handle<AttackDefinition>,
+    next: String,
+    pub(crate) window: (f32, f32),
+    pub(crate) reset: f32,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Keyframe {
+    pub(crate) at: f32,
+    pub(crate) event: AttackEvent,
+}
+
+#[derive(Deserialize)]
+pub(crate) enum AttackEvent {
+    EndAttack,
+    Invulnerable(f32),
+    PlaySound(SoundDefinition),
+    SpawnHitbox(HitboxDefinition),
+    StartMovement(MovementDefinition),
+    StartSwing(SwingDefinition),
+}
+
+#[derive(Deserialize)]
+pub(crate) struct HitboxDefinition {
+    #[serde(default)]
+    pub(crate) damage: i16,
+    #[serde(default)]
+    pub(crate) damage_type: DamageType,
+    pub(crate) duration: f32,
+    #[serde(default)]
+    pub(crate) hitstun: f32,
+    #[serde(default)]
+    pub(crate) hits: HitPolicy,
+    #[serde(default)]
+    pub(crate) knockback: f32,
+    #[serde(default)]
+    pub(crate) marker: Option<AttackMarker>,
+    #[serde(default)]
+    pub(crate) parry: bool,
+    #[serde(default)]
+    pub(crate) potion: bool,
+    #[serde(default)]
+    pub(crate) projectile: Option<ProjectileDefinition>,
+    pub(crate) range: f32,
+    pub(crate) shape: HitboxShape,
+    #[serde(default)]
+    pub(crate) sound: Option<SoundDefinition>,
+    #[serde(default)]
+    pub(crate) sprite: Option<SpriteDefinition>,
+    #[serde(default)]
+    pub(crate) swing: Option<SwingDefinition>,
+    #[serde(default)]
+    pub(crate) thrown: Option<ThrownDefinition>,
+}
+
+#[derive(Deserialize, Reflect, Clone, Copy, Default)]
+pub(crate) enum HitPolicy {
+    // Seconds before the same target can be hit again.
+    Interval(f32),
+    #[default]
+    Once,
+    // Targets hit before the hitbox stops hitting.
+    Pierce(u8),
+}
+
+#[derive(Deserialize, Clone, Copy)]
+pub(crate) enum HitboxShape {
+    Circle(f32),
+    Rectangle(f32, f32),
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ProjectileDefinition {
+    pub(crate) angular_speed: f32,
+    pub(crate) speed: f32,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ThrownDefinition {
+    pub(crate) angular_speed: f32,
+    pub(crate) height: f32,
+    pub(crate) max_range: f32,
+    pub(crate) speed: f32,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct MovementDefinition {
+    pub(crate) direction: MovementDirection,
+    pub(crate) duration: f32,
+    pub(crate) easing: EaseFunction,
+    pub(crate) speed: f32,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+pub(crate) enum MovementDirection {
+    Backward,
+    Forward,
+    Input,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SwingDefinition {
+    pub(crate) distance: f32,
+    pub(crate) duration: f32,
+    pub(crate) easing: EaseFunction,
+    #[serde(default = "unscaled")]
+    pub(crate) scale: Vec3,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SoundDefinition {
+    #[serde(skip)]
+    pub(crate) handle: Handle<Sample>,
+    path: String,
+    #[serde(default = "full_volume")]
+    volume: f32,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SpriteDefinition {
+    #[serde(skip)]
+    pub(crate) handle: Handle<Image>,
+    path: String,
+    #[serde(default)]
+    size: Option<Vec2>,
+}
+
+#[derive(Default)]
+pub(crate) struct AttackDefinitionLoader;
+
+#[derive(Debug)]
+pub(crate) enum AttackDefinitionError {
+    Invalid(&'static str),
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+fn full_volume() -> f32 {
+    1.
+}
+
+fn unscaled() -> Vec3 {
+    Vec3::ONE
+}
+
+fn unchanged() -> f32 {
+    1.
+}
+
+impl AttackDefinition {
+    pub(crate) fn charge_scale(&self, held: f32) -> ChargeScale {
+        self.charge
+            .as_ref()
+            .map_or(ChargeScale::NONE, |charge| charge.scale(held))
+    }
+
+    pub(crate) fn recovering(&self, next_keyframe: usize) -> bool {
+        !self.timeline[next_keyframe.min(self.timeline.len())..]
+            .iter()
+            .any(|keyframe| matches!(keyframe.event, AttackEvent::SpawnHitbox(_)))
+    }
+
+    pub(crate) fn rooted(&self) -> Rooted {
+        Rooted {
+            duration: Duration::from_secs_f32(self.rooted),
+            stopwatch: Stopwatch::new(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), AttackDefinitionError> {
+        let Some((last, keyframes)) = self.timeline.split_last() else {
+            return Err(AttackDefinitionError::Invalid("timeline is empty"));
+        };
+
+        if !self.cooldown.is_finite() || self.cooldown < 0. {
+            return Err(AttackDefinitionError::Invalid("cooldown is negative"));
+        }
+
+        if !self.cost.is_finite() || self.cost < 0. {
+            return Err(AttackDefinitionError::Invalid("cost is negative"));
+        }
+
+        if !self.rooted.is_finite() || self.rooted < 0. {
+            return Err(AttackDefinitionError::Invalid("rooted is negative"));
+        }
+
+        if !matches!(last.event, AttackEvent::EndAttack) {
+            return Err(AttackDefinitionError::Invalid(
+                "timeline does not finish with EndAttack",
+            ));
+        }
+
+        if keyframes
+            .iter()
+            .any(|keyframe| matches!(keyframe.event, AttackEvent::EndAttack))
+        {
+            return Err(AttackDefinitionError::Invalid(
+                "EndAttack is not the last keyframe",
+            ));
+        }
+
+        if self
+            .timeline
+            .iter()
+            .any(|keyframe| !keyframe.at.is_finite() || keyframe.at < 0.)
+        {
+            return Err(AttackDefinitionError::Invalid(
+                "keyframe time is negative or not finite",
+            ));
+        }
+
+        if !self.timeline.is_sorted_by(|a, b| a.at <= b.at) {
+            return Err(AttackDefinitionError::Invalid(
+                "keyframes are not sorted by time",
+            ));
+        }
+
+        for keyframe in &self.timeline {
+            match &keyframe.event {
+                AttackEvent::Invulnerable(duration) if !duration.is_finite() || *duration < 0. => {
+                    return Err(AttackDefinitionError::Invalid(
+                        "invulnerability is negative",
+                    ));
+                }
+                AttackEvent::SpawnHitbox(hitbox) => hitbox.validate()?,
+                AttackEvent::StartMovement(movement) => movement.validate()?,
+                AttackEvent::StartSwing(swing) => swing.validate()?,
+                _ => {}
+            }
+        }
+
+        if let Some(combo) = &self.combo {
+            combo.validate()?;
+        }
+
+        if let Some(charge) = &self.charge {
+            charge.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ChargeDefinition {
+    pub(crate) fn level(&self, held: f32) -> f32 {
+        (held / self.time).clamp(0., 1.)
+    }
+
+    fn scale(&self, held: f32) -> ChargeScale {
+        let level = self.level(held);
+        let scale = |full: f32| 1. + (full - 1.) * level;
+
+        ChargeScale {
+            damage: scale(self.damage),
+            range: scale(self.range),
+            size: scale(self.size),
+        }
+    }
+
+    fn validate(&self) -> Result<(), AttackDefinitionError> {
+        if !self.time.is_finite() || self.time <= 0. {
+            return Err(AttackDefinitionError::Invalid(
+                "charge time is not positive",
+            ));
+        }
+
+        if [self.damage, self.range, self.size]
+            .iter()
+            .any(|multiplier| !multiplier.is_finite() || *multiplier <= 0.)
+        {
+            return Err(AttackDefinitionError::Invalid(
+                "charge multiplier is not positive",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl ChargeScale {
+    pub(crate) const NONE: Self = Self {
+        damage: 1.,
+        range: 1.,
+        size: 1.,
+    };
+}
+
+impl ComboDefinition {
+    pub(crate) fn combo(&self, chain: AssetId<AttackDefinition>) -> Combo {
+        Combo {
+            chain,
+            next: self.handle.clone_weak(),
+            window: (
+                Duration::from_secs_f32(self.window.0),
+                Duration::from_secs_f32(self.window.1),
+            ),
+            reset: Duration::from_secs_f32(self.reset),
+            stopwatch: Stopwatch::new(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), AttackDefinitionError> {
+        let (start, end) = self.window;
+
+        if !start.is_finite() || !end.is_finite() || start < 0. || start > end {
+            return Err(AttackDefinitionError::Invalid(
+                "combo window is negative or reversed",
+            ));
+        }
+
+        if !self.reset.is_finite() || self.reset < end {
+            return Err(AttackDefinitionError::Invalid(
+                "combo resets before its window closes",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl HitboxDefinition {
+    pub(crate) fn duration(&self) -> Duration {
+        Duration::from_secs_f32(self.duration)
+    }
+
+    pub(crate) fn hitstun(&self) -> Duration {
+        Duration::from_secs_f32(self.hitstun)
+    }
+
+    pub(crate) fn movement(&self, direction: Vec2) -> Option<(LinearVelocity, AngularVelocity)> {
+        self.projectile.as_ref().map(|projectile| {
+            (
+                LinearVelocity(direction * projectile.speed),
+                AngularVelocity(projectile.angular_speed),
+            )
+        })
+    }
+
+    pub(crate) fn swings(&self, direction: Vec2) -> Option<Swings> {
+        self.swing.as_ref().map(|swing| {
+            let from = Transform::from_translation((direction * swing.distance).extend(0.));
+            swing.swings(from, Vec3::ZERO, direction)
+        })
+    }
+
+    fn validate(&self) -> Result<(), AttackDefinitionError> {
+        if !self.duration.is_finite() || self.duration <= 0. {
+            return Err(AttackDefinitionError::Invalid(
+                "hitbox duration is not positive",
+            ));
+        }
+
+        if !self.hitstun.is_finite() || self.hitstun < 0. {
+            return Err(AttackDefinitionError::Invalid("hitstun is negative"));
+        }
+
+        match self.hits {
+            HitPolicy::Interval(interval) if !interval.is_finite() || interval <= 0. => {
+                return Err(AttackDefinitionError::Invalid(
+                    "hit interval is not positive",
+                ));
+            }
+            HitPolicy::Pierce(0) => {
+                return Err(AttackDefinitionError::Invalid("hitbox pierces no targets"));
+            }
+            _ => {}
+        }
+
+        let empty = match self.shape {
+            HitboxShape::Circle(radius) => !radius.is_finite() || radius <= 0.,
+            HitboxShape::Rectangle(width, height) => {
+                !width.is_finite() || !height.is_finite() || width <= 0. || height <= 0.
+            }
+        };
+
+        if empty {
+            return Err(AttackDefinitionError::Invalid("hitbox shape is empty"));
+        }
+
+        if let Some(swing) = &self.swing {
+            swing.validate()?;
+        }
+
+        if let Some(thrown) = &self.thrown {
+            if self.projectile.is_some() {
+                return Err(AttackDefinitionError::Invalid(
+                    "hitbox is both a projectile and thrown",
+                ));
+            }
+
+            if thrown.speed <= 0. || thrown.max_range <= 0. {
+                return Err(AttackDefinitionError::Invalid(
+                    "thrown speed or range is not positive",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ThrownDefinition {
+    pub(crate) fn landing(
+        &self,
+        origin: Vec2,
+        direction: Vec2,
+        target: Option<Vec2>,
+        range: f32,
+    ) -> Vec2 {
+        let max_range = self.max_range * range;
+
+        match target {
+            Some(target) => origin + (target - origin).clamp_length_max(max_range),
+            None => origin + direction * max_range,
+        }
+    }
+}
+
+impl HitboxShape {
+    pub(crate) fn collider(self) -> Collider {
+        match self {
+            HitboxShape::Circle(radius) => Collider::circle(radius),
+            HitboxShape::Rectangle(width, height) => Collider::rectangle(width, height),
+        }
+    }
+
+    pub(crate) fn scaled(self, factor: f32) -> Self {
+        match self {
+            HitboxShape::Circle(radius) => HitboxShape::Circle(radius * factor),
+            HitboxShape::Rectangle(width, height) => {
+                HitboxShape::Rectangle(width * factor, height * factor)
+            }
+        }
+    }
+}
+
+impl MovementDefinition {
+    pub(crate) fn attack_movements(&self, direction: Vec2, input: Vec2) -> AttackMovements {
+        let from = match self.direction {
+            MovementDirection::Backward => -direction,
+            MovementDirection::Forward => direction,
+            MovementDirection::Input => input,
+        };
+
+        AttackMovements {
+            movements: vec![(
+                Duration::ZERO,
+                AttackMovement {
+                    easing: self.easing,
+                    duration: Duration::from_secs_f32(self.duration),
+                    from_to: (from, Vec2::ZERO),
+                    speed: self.speed,
+                },
+            )],
+            stopwatch: Stopwatch::new(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), AttackDefinitionError> {
+        if !self.duration.is_finite() || self.duration <= 0. {
+            return Err(AttackDefinitionError::Invalid(
+                "movement duration is not positive",
+            ));
+        }
+
+        if !self.speed.is_finite() {
+            return Err(AttackDefinitionError::Invalid(
+                "movement speed is not finite",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl SwingDefinition {
+    pub(crate) fn swings(&self, from: Transform, origin: Vec3, direction: Vec2) -> Swings {
+        let to = Transform::from_translation(origin + (direction * self.distance).extend(0.))
+            .with_rotation(Quat::from_rotation_arc(Vec3::Y, direction.extend(0.)))
+            .with_scale(self.scale);
+
+        Swings {
+            swings: vec![(
+                Duration::ZERO,
+                Swing {
+                    from,
+                    to,
+                    duration: Duration::from_secs_f32(self.duration),
+                    easing: self.easing,
+                },
+            )],
+            stopwatch: Stopwatch::new(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), AttackDefinitionError> {
+        if !self.duration.is_finite() || self.duration <= 0. {
+            return Err(AttackDefinitionError::Invalid(
+                "swing duration is not positive",
+            ));
+        }
+
+        if !self.distance.is_finite() || !self.scale.is_finite() {
+            return Err(AttackDefinitionError::Invalid(
+                "swing distance or scale is not finite",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl SoundDefinition {
+    pub(crate) fn player(&self) -> SamplePlayer {
+        SamplePlayer::new(self.handle.clone_weak()).with_volume(Volume::Linear(self.volume))
+    }
+}
+
+impl SpriteDefinition {
+    pub(crate) fn sprite(&self) -> Sprite {
+        Sprite {
+            image: self.handle.clone_weak(),
+            custom_size: self.size,
+            ..default()
+        }
+    }
+}
+
+impl AssetLoader for AttackDefinitionLoader {
+    type Asset = AttackDefinition;
+    type Settings = ();
+    type Error = AttackDefinitionError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut definition: AttackDefinition = ron::de::from_bytes(&bytes)?;
+        definition.validate()?;
+
+        if let Some(combo) = &mut definition.combo {
+            combo.handle = load_context.load(combo.next.clone());
+        }
+
+        for keyframe in &mut definition.timeline {
+            match &mut keyframe.event {
+                AttackEvent::PlaySound(sound) => {
+                    sound.handle = load_context.load(sound.path.clone());
+                }
+                AttackEvent::SpawnHitbox(hitbox) => {
+                    if let Some(sound) = &mut hitbox.sound {
+                        sound.handle = load_context.load(sound.path.clone());
+                    }
+
+                    if let Some(sprite) = &mut hitbox.sprite {
+                        sprite.handle = load_context.load(sprite.path.clone());
+                    }
+                }
+                AttackEvent::EndAttack
+                | AttackEvent::Invulnerable(_)
+                | AttackEvent::StartMovement(_)
+                | AttackEvent::StartSwing(_) => {}
+            }
+        }
+
+        Ok(definition)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["attack.ron"]
+    }
+}
+
+impl fmt::Display for AttackDefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttackDefinitionError::Invalid(reason) => write!(f, "invalid attack: {reason}"),
+            AttackDefinitionError::Io(error) => write!(f, "could not read attack: {error}"),
+            AttackDefinitionError::Ron(error) => write!(f, "could not parse attack: {error}"),
+        }
+    }
+}
+
+impl Error for AttackDefinitionError {}
+
+impl From<std::io::Error> for AttackDefinitionError {
+    fn from(error: std::io::Error) -> Self {
+        AttackDefinitionError::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for AttackDefinitionError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        AttackDefinitionError::Ron(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(ron: &str) -> AttackDefinition {
+        ron::de::from_str(ron).unwrap()
+    }
+
+    fn rejection(ron: &str) -> &'static str {
+        match parse(ron).validate() {
+            Err(AttackDefinitionError::Invalid(reason)) => reason,
+            Err(error) => panic!("unexpected error {error}"),
+            Ok(()) => panic!("definition was accepted"),
+        }
+    }
+
+    fn hitbox(fields: &str) -> String {
+        format!(
+            "(rooted: 0.5, timeline: [
+                (at: 0.1, event: SpawnHitbox((range: 10.0, duration: 0.2, shape: Circle(5.0), {fields}))),
+                (at: 0.2, event: EndAttack),
+            ])"
+        )
+    }
+
+    #[test]
+    fn accepts_a_valid_timeline() {
+        let definition = parse(&hitbox("damage: 5, hits: Pierce(2)"));
+
+        assert!(definition.validate().is_ok());
+    }
+
+    #[test]
+    fn rejects_an_empty_timeline() {
+        assert_eq!(
+            rejection("(rooted: 0.5, timeline: [])"),
+            "timeline is empty"
+        );
+    }
+
+    #[test]
+    fn rejects_a_timeline_without_end_attack_last() {
+        assert_eq!(
+            rejection("(rooted: 0.5, timeline: [(at: 0.0, event: Invulnerable(0.1))])"),
+            "timeline does not finish with EndAttack",
+        );
+        assert_eq!(
+            rejection(
+                "(rooted: 0.5, timeline: [(at: 0.0, event: EndAttack), (at: 0.1, event: EndAttack)])"
+            ),
+            "EndAttack is not the last keyframe",
+        );
+    }
+
+    #[test]
+    fn rejects_unsorted_keyframes() {
+        assert_eq!(
+            rejection(
+                "(rooted: 0.5, timeline: [(at: 0.3, event: Invulnerable(0.1)), (at: 0.2, event: EndAttack)])"
+            ),
+            "keyframes are not sorted by time",
+        );
+    }
+
+    #[test]
+    fn rejects_negative_durations() {
+        assert_eq!(
+            rejection("(rooted: -1.0, timeline: [(at: 0.0, event: EndAttack)])"),
+            "rooted is negative",
+        );
+        assert_eq!(
+            rejection("(cooldown: -1.0, rooted: 0.5, timeline: [(at: 0.0, event: EndAttack)])"),
+            "cooldown is negative",
+        );
+        assert_eq!(
+            rejection(
+                "(rooted: 0.5, timeline: [
+                    (at: 0.0, event: StartMovement((direction: Forward, duration: -0.1, speed: 10.0, easing: Linear))),
+                    (at: 0.1, event: EndAttack),
+                ])"
+            ),
+            "movement duration is not positive",
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_hitboxes() {
+        let empty = "(rooted: 0.5, timeline: [
+            (at: 0.1, event: SpawnHitbox((range: 10.0, duration: 0.2, shape: Rectangle(0.0, 4.0)))),
+            (at: 0.2, event: EndAttack),
+        ])";
+
+        assert_eq!(rejection(empty), "hitbox shape is empty");
+        assert_eq!(
+            rejection(&hitbox("hits: Pierce(0)")),
+            "hitbox pierces no targets"
+        );
+        assert_eq!(
+            rejection(&hitbox("hits: Interval(0.0)")),
+            "hit interval is not positive",
+        );
+        assert_eq!(rejection(&hitbox("hitstun: -0.1")), "hitstun is negative");
+    }
+
+    #[test]
+    fn scales_with_the_charge_held() {
+        let definition = parse(
+            "(charge: Some((time: 1.0, damage: 3.0)), rooted: 0.5, timeline: [(at: 0.0, event: EndAttack)])",
+        );
+
+        assert_eq!(definition.charge_scale(0.).damage, 1.);
+        assert_eq!(definition.charge_scale(0.5).damage, 2.);
+        assert_eq!(definition.charge_scale(5.).damage, 3.);
+        assert_eq!(definition.charge_scale(5.).range, 1.);
+    }
+
+    #[test]
+    fn recovers_after_the_last_hitbox() {
+        let definition = parse(&hitbox(""));
+
+        assert!(!definition.recovering(0));
+        assert!(definition.recovering(1));
+    }
+
+    #[test]
+    fn pulls_throws_in_to_their_range() {
+        let thrown = ThrownDefinition {
+            angular_speed: 0.,
+            height: 10.,
+            max_range: 100.,
+            speed: 50.,
+        };
+
+        assert_eq!(
+            thrown.landing(Vec2::ZERO, Vec2::X, Some(Vec2::new(0., 300.)), 1.),
+            Vec2::new(0., 100.),
+        );
+        assert_eq!(
+            thrown.landing(Vec2::ZERO, Vec2::X, Some(Vec2::new(0., 50.)), 1.),
+            Vec2::new(0., 50.),
+        );
+        assert_eq!(
+            thrown.landing(Vec2::ZERO, Vec2::X, None, 1.5),
+            Vec2::new(150., 0.)
+        );
+    }
+}
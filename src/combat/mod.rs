@@ -0,0 +1,832 @@
+mod damage;
+mod definition;
+mod thrown;
+
+use std::time::Duration;
+
+use avian2d::prelude::{
+    ColliderDisabled, CollidingEntities, CollisionEventsEnabled, CollisionLayers, LinearVelocity,
+    RigidBody, Sensor, TransformInterpolation,
+};
+use bevy::{platform::collections::HashMap, prelude::*, time::Stopwatch};
+
+use crate::{
+    AppliesMark, AttackMarker, GameCollisionLayer, TriggersMark, ZLayer,
+    audio::HitboxSound,
+    enemy::Enemy,
+    movement::{Moving, Rooted, Stunned},
+    player::{Player, Potion, PotionDefinition},
+};
+
+use crate::combat::definition::{AttackEvent, ChargeScale, HitPolicy, HitboxDefinition};
+
+pub(super) use crate::combat::damage::{
+    DamageEvent, DamageKind, DamageModifiers, DamageType, Damaged, Empowered, Invulnerable,
+    InvulnerableOnHit, Killed, Resistances, apply_damage, despawn_killed, tick_empowered,
+    tick_invulnerable,
+};
+pub(super) use crate::combat::definition::{AttackDefinition, AttackDefinitionLoader};
+pub(super) use crate::combat::thrown::{Landed, Thrown, ThrownSprite, move_thrown};
+
+#[derive(Event, Clone)]
+pub(crate) struct AttackRequested {
+    pub(crate) attacker: Entity,
+    pub(crate) attack: Handle<AttackDefinition>,
+    pub(crate) charge: f32,
+    pub(crate) direction: Vec2,
+    pub(crate) potion: Option<PotionDefinition>,
+    pub(crate) potion_slot: Option<usize>,
+    pub(crate) target: Option<Vec2>,
+}
+
+#[derive(Event)]
+pub(crate) struct AttackFailed {
+    pub(crate) reason: AttackFailure,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum AttackFailure {
+    Cooldown,
+    Essence,
+}
+
+#[derive(Component)]
+pub(crate) struct AttackBuffer {
+    request: Option<AttackRequested>,
+    window: Timer,
+}
+
+#[derive(Component, Default)]
+pub(super) struct AttackCooldowns(HashMap<AssetId<AttackDefinition>, Timer>);
+
+#[derive(Component)]
+pub(super) struct Attacking {
+    pub(super) attack: Handle<AttackDefinition>,
+    pub(super) charge: ChargeScale,
+    pub(super) direction: Vec2,
+    pub(super) heading: Vec2,
+    pub(super) next_keyframe: usize,
+    pub(super) potion: Option<PotionDefinition>,
+    pub(super) potion_slot: Option<usize>,
+    pub(super) stopwatch: Stopwatch,
+    pub(super) target: Option<Vec2>,
+}
+
+#[derive(Component)]
+pub(super) struct Combo {
+    pub(super) chain: AssetId<AttackDefinition>,
+    pub(super) next: Handle<AttackDefinition>,
+    pub(super) window: (Duration, Duration),
+    pub(super) reset: Duration,
+    pub(super) stopwatch: Stopwatch,
+}
+
+#[derive(Component, Reflect)]
+pub(super) struct AttackMovements {
+    pub(super) movements: Vec<(Duration, AttackMovement)>,
+    pub(super) stopwatch: Stopwatch,
+}
+
+#[derive(Reflect)]
+pub(super) struct AttackMovement {
+    pub(super) easing: EaseFunction,
+    pub(super) duration: Duration,
+    pub(super) from_to: (Vec2, Vec2),
+    pub(super) speed: f32,
+}
+#[derive(Component, Reflect, Clone)]
+pub(super) struct Swings {
+    pub(super) swings: Vec<(Duration, Swing)>,
+    pub(super) stopwatch: Stopwatch,
+}
+
+#[derive(Reflect, Clone)]
+pub(super) struct Swing {
+    pub(super) from: Transform,
+    pub(super) to: Transform,
+    pub(super) duration: Duration,
+    pub(super) easing: EaseFunction,
+}
+
+#[derive(Component, Reflect)]
+pub(super) struct Health {
+    pub(super) current: i16,
+    pub(super) max: i16,
+}
+
+#[derive(Component, Reflect)]
+pub(crate) struct HealthBar;
+
+#[derive(Component, Reflect)]
+pub(crate) struct Essence {
+    pub(crate) current: f32,
+    pub(crate) max: f32,
+    pub(crate) regen: f32,
+}
+
+#[derive(Component, Reflect)]
+pub(crate) struct EssenceBar;
+
+#[derive(Component, Reflect, DerefMut, Deref)]
+pub(super) struct AttackHitBoxTimer(pub(super) Timer);
+
+#[derive(Component, Reflect)]
+pub(super) struct Hitbox {
+    pub(super) attacker: Entity,
+    pub(super) damage: i16,
+    pub(super) damage_type: DamageType,
+    pub(super) hitstun: Duration,
+    pub(super) knockback: f32,
+}
+
+#[derive(Component, Reflect)]
+pub(super) struct HitRegistry {
+    policy: HitPolicy,
+    hits: HashMap<Entity, Duration>,
+    just_hit: Vec<Entity>,
+    stopwatch: Stopwatch,
+}
+
+impl HitRegistry {
+    fn new(policy: HitPolicy) -> Self {
+        Self {
+            policy,
+            hits: HashMap::default(),
+            just_hit: Vec::new(),
+            stopwatch: Stopwatch::new(),
+        }
+    }
+
+    fn register(&mut self, target: Entity) -> bool {
+        let now = self.stopwatch.elapsed();
+
+        let allowed = match (self.policy, self.hits.get(&target)) {
+            (HitPolicy::Interval(interval), Some(last)) => (now - *last).as_secs_f32() >= interval,
+            (_, Some(_)) => false,
+            (HitPolicy::Pierce(targets), None) => self.hits.len() < usize::from(targets),
+            (_, None) => true,
+        };
+
+        if allowed {
+            self.hits.insert(target, now);
+            self.just_hit.push(target);
+        }
+
+        allowed
+    }
+
+    fn exhausted(&self) -> bool {
+        matches!(self.policy, HitPolicy::Pierce(targets) if self.hits.len() >= usize::from(targets))
+    }
+
+    pub(super) fn just_hit(&self) -> &[Entity] {
+        &self.just_hit
+    }
+}
+
+#[derive(Component, Reflect)]
+pub(super) struct Parries;
+
+#[derive(Component, Reflect)]
+#[relationship(relationship_target = Wielding)]
+pub(super) struct WieldedBy(pub(super) Entity);
+
+#[derive(Component, Reflect)]
+#[relationship_target(relationship = WieldedBy)]
+pub(super) struct Wielding(Entity);
+
+impl Combo {
+    fn cancelable(&self) -> bool {
+        (self.window.0..=self.window.1).contains(&self.stopwatch.elapsed())
+    }
+}
+
+impl AttackBuffer {
+    pub(crate) fn new(window: f32) -> Self {
+        Self {
+            request: None,
+            window: Timer::from_seconds(window, TimerMode::Once),
+        }
+    }
+
+    fn store(&mut self, request: AttackRequested) {
+        self.request = Some(request);
+        self.window.reset();
+    }
+}
+
+impl Essence {
+    pub(crate) fn fraction(&self) -> f32 {
+        (self.current / self.max).clamp(0., 1.)
+    }
+}
+
+impl AttackCooldowns {
+    pub(crate) fn ready(&self, attack: AssetId<AttackDefinition>) -> bool {
+        !self.0.contains_key(&attack)
+    }
+
+    pub(crate) fn fraction_remaining(&self, attack: AssetId<AttackDefinition>) -> f32 {
+        self.0
+            .get(&attack)
+            .map_or(0., |timer| timer.fraction_remaining())
+    }
+
+    fn start(&mut self, attack: AssetId<AttackDefinition>, duration: Duration) {
+        if !duration.is_zero() {
+            self.0.insert(attack, Timer::new(duration, TimerMode::Once));
+        }
+    }
+}
+
+pub(super) fn request_attack(
+    trigger: Trigger<AttackRequested>,
+    mut commands: Commands,
+    mut attacker_q: Query<
+        (
+            &LinearVelocity,
+            &mut AttackCooldowns,
+            Option<&Attacking>,
+            Option<&Combo>,
+            Option<&mut Essence>,
+            Option<&mut AttackBuffer>,
+        ),
+        Without<Stunned>,
+    >,
+    attacks: Res<Assets<AttackDefinition>>,
+) {
+    let request = trigger.event();
+
+    let Ok((velocity, mut cooldowns, attacking, combo, essence, buffer)) =
+        attacker_q.get_mut(request.attacker)
+    else {
+        return;
+    };
+
+    let combo = combo.filter(|combo| combo.chain == request.attack.id());
+
+    let (handle, chain) = match combo {
+        Some(combo) => (&combo.next, combo.chain),
+        None => (&request.attack, request.attack.id()),
+    };
+
+    let Some(attack) = attacks.get(handle) else {
+        return;
+    };
+
+    // Only the next stage of a combo, or an attack that cancels recovery once the current one is
+    // done hitting, may interrupt an attack.
+    if let Some(attacking) = attacking
+        && !combo.is_some_and(Combo::cancelable)
+        && !(attack.cancels_recovery
+            && attacks
+                .get(&attacking.attack)
+                .is_some_and(|current| current.recovering(attacking.next_keyframe)))
+    {
+        if let Some(mut buffer) = buffer {
+            buffer.store(request.clone());
+        }
+
+        return;
+    }
+
+    let failure = if !cooldowns.ready(handle.id()) {
+        Some(AttackFailure::Cooldown)
+    } else if essence
+        .as_ref()
+        .is_some_and(|essence| essence.current < attack.cost)
+    {
+        Some(AttackFailure::Essence)
+    } else {
+        None
+    };
+
+    if let Some(reason) = failure {
+        commands
+            .entity(request.attacker)
+            .trigger(AttackFailed { reason });
+        return;
+    }
+
+    cooldowns.start(handle.id(), Duration::from_secs_f32(attack.cooldown));
+
+    if let Some(mut essence) = essence {
+        essence.current -= attack.cost;
+    }
+
+    let mut entity_commands = commands.entity(request.attacker);
+
+    match &attack.combo {
+        Some(next) => entity_commands.insert(next.combo(chain)),
+        None => entity_commands.remove::<Combo>(),
+    };
+
+    entity_commands.remove::<Moving>().insert((
+        Attacking {
+            attack: handle.clone_weak(),
+            charge: attack.charge_scale(request.charge),
+            direction: request.direction.normalize_or_zero(),
+            heading: velocity.normalize_or_zero(),
+            next_keyframe: 0,
+            potion: request.potion,
+            potion_slot: request.potion_slot,
+            stopwatch: Stopwatch::new(),
+            target: request.target,
+        },
+        attack.rooted(),
+    ));
+}
+
+pub(super) fn replay_buffered_attacks(
+    mut commands: Commands,
+    buffer_q: Query<(
+        &mut AttackBuffer,
+        &AttackCooldowns,
+        Has<Attacking>,
+        Option<&Combo>,
+    )>,
+    time: Res<Time<Virtual>>,
+) {
+    for (mut buffer, cooldowns, attacking, combo) in buffer_q {
+        let Some(request) = &buffer.request else {
+            continue;
+        };
+
+        let combo = combo.filter(|combo| combo.chain == request.attack.id());
+        let attack = combo.map_or(request.attack.id(), |combo| combo.next.id());
+
+        // Replaying an attack that is still cooling down would only fail and lose the input.
+        let ready = (!attacking || combo.is_some_and(Combo::cancelable)) && cooldowns.ready(attack);
+
+        if ready {
+            if let Some(request) = buffer.request.take() {
+                commands.trigger(request);
+            }
+        } else if buffer.window.tick(time.delta()).finished() {
+            buffer.request = None;
+        }
+    }
+}
+
+pub(super) fn regenerate_essence(essence_q: Query<&mut Essence>, time: Res<Time<Virtual>>) {
+    for mut essence in essence_q {
+        if essence.current < essence.max {
+            essence.current =
+                (essence.current + essence.regen * time.delta_secs()).min(essence.max);
+        }
+    }
+}
+
+pub(super) fn hitbox_hit(
+    mut commands: Commands,
+    hitbox_q: Query<(
+        Entity,
+        &Hitbox,
+        &mut HitRegistry,
+        &CollidingEntities,
+        &GlobalTransform,
+    )>,
+    target_q: Query<
+        (
+            &GlobalTransform,
+            Option<&InvulnerableOnHit>,
+            Has<Invulnerable>,
+        ),
+        With<Health>,
+    >,
+    mut damage: EventWriter<DamageEvent>,
+    time: Res<Time<Virtual>>,
+) {
+    // Invulnerability is only inserted once the commands run, so overlapping hitboxes in the same
+    // frame check this as well.
+    let mut made_invulnerable = Vec::new();
+
+    for (entity, hitbox, mut registry, colliding_entities, hitbox_transform) in hitbox_q {
+        registry.stopwatch.tick(time.delta());
+        registry.just_hit.clear();
+
+        for &target in colliding_entities.iter() {
+            let Ok((target_transform, invulnerable_on_hit, invulnerable)) = target_q.get(target)
+            else {
+                continue;
+            };
+
+            // A dodged hit still counts, the hitbox doesn't get another try once it's over.
+            if !registry.register(target) || invulnerable || made_invulnerable.contains(&target) {
+                continue;
+            }
+
+            let position = hitbox_transform.translation().xy();
+
+            if hitbox.damage > 0 {
+                damage.write(DamageEvent {
+                    target,
+                    source: hitbox.attacker,
+                    amount: hitbox.damage,
+                    damage_type: hitbox.damage_type,
+                    kind: DamageKind::Hit,
+                    position,
+                });
+
+                if let Some(InvulnerableOnHit(duration)) = invulnerable_on_hit {
+                    commands.entity(target).insert(Invulnerable {
+                        duration: *duration,
+                        stopwatch: Stopwatch::new(),
+                    });
+                    made_invulnerable.push(target);
+                }
+            }
+
+            if !hitbox.hitstun.is_zero() {
+                let direction = (target_transform.translation().xy() - position)
+                    .try_normalize()
+                    .unwrap_or_else(|| hitbox_transform.up().xy());
+
+                stagger(
+                    &mut commands.entity(target),
+                    hitbox.hitstun,
+                    direction * hitbox.knockback,
+                );
+            }
+        }
+
+        // Left to its timer, so its sprite finishes the swing.
+        if registry.exhausted() {
+            commands.entity(entity).try_insert(ColliderDisabled);
+        }
+    }
+}
+
+pub(crate) fn stagger(entity_commands: &mut EntityCommands, duration: Duration, knockback: Vec2) {
+    // Hitstun interrupts whatever the target was doing, including the recovery of its attack.
+    entity_commands
+        .remove::<(Moving, Attacking, AttackMovements, Combo, Rooted)>()
+        .insert(Stunned {
+            duration,
+            knockback,
+            stopwatch: Stopwatch::new(),
+        });
+}
+
+pub(super) fn tick_combos(
+    mut commands: Commands,
+    combo_q: Query<(Entity, &mut Combo)>,
+    time: Res<Time<Virtual>>,
+) {
+    for (entity, mut combo) in combo_q {
+        if combo.stopwatch.tick(time.delta()).elapsed() >= combo.reset {
+            commands.entity(entity).remove::<Combo>();
+        }
+    }
+}
+
+pub(super) fn tick_attack_cooldowns(
+    cooldowns_q: Query<&mut AttackCooldowns>,
+    time: Res<Time<Virtual>>,
+) {
+    for mut cooldowns in cooldowns_q {
+        cooldowns
+            .0
+            .retain(|_, timer| !timer.tick(time.delta()).finished());
+    }
+}
+
+pub(super) fn tick_hitbox_timer(
+    mut commands: Commands,
+    timer_q: Query<(Entity, &mut AttackHitBoxTimer)>,
+    time: Res<Time<Virtual>>,
+) {
+    for (entity, mut timer) in timer_q {
+        if timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub(super) fn tick_attack_timer(
+    mut commands: Commands,
+    attacking_q: Query<(
+        Entity,
+        &mut Attacking,
+        &Transform,
+        Option<&Wielding>,
+        Option<&Invulnerable>,
+        Has<Enemy>,
+        Has<Player>,
+    )>,
+    weapon_q: Query<&Transform, With<WieldedBy>>,
+    attacks: Res<Assets<AttackDefinition>>,
+    time: Res<Time<Virtual>>,
+) {
+    for (entity, mut attacking, transform, wielding, invulnerable, is_enemy, is_player) in
+        attacking_q
+    {
+        attacking.stopwatch.tick(time.delta());
+
+        let Some(attack) = attacks.get(&attacking.attack) else {
+            commands.entity(entity).remove::<Attacking>();
+            continue;
+        };
+
+        let elapsed = attacking.stopwatch.elapsed_secs();
+
+        while let Some(keyframe) = attack.timeline.get(attacking.next_keyframe)
+            && keyframe.at <= elapsed
+        {
+            attacking.next_keyframe += 1;
+
+            match &keyframe.event {
+                AttackEvent::EndAttack => {
+                    commands.entity(entity).remove::<Attacking>();
+                }
+                AttackEvent::Invulnerable(duration) => {
+                    Invulnerable::grant(
+                        &mut commands.entity(entity),
+                        invulnerable,
+                        Duration::from_secs_f32(*duration),
+                    );
+                }
+                AttackEvent::PlaySound(sound) => {
+                    commands.spawn(sound.player());
+                }
+                AttackEvent::SpawnHitbox(hitbox) => {
+                    let (layer, z_layer) = if is_enemy {
+                        (
+                            GameCollisionLayer::enemy_attack(),
+                            ZLayer::EnemyWeapon.z_layer(),
+                        )
+                    } else if is_player {
+                        (
+                            GameCollisionLayer::player_attack(),
+                            ZLayer::PlayerWeapon.z_layer(),
+                        )
+                    } else if is_enemy && is_player {
+                        panic!("Entity is player and enemy?")
+                    } else {
+                        panic!("Entity is neither player nor enemy?")
+                    };
+
+                    spawn_hitbox(
+                        &mut commands,
+                        entity,
+                        transform,
+                        hitbox,
+                        attacking.direction,
+                        attacking.potion.filter(|_| hitbox.potion),
+                        attacking.target,
+                        attacking.charge,
+                        layer,
+                        z_layer,
+                    );
+                }
+                AttackEvent::StartMovement(movement) => {
+                    commands
+                        .entity(entity)
+                        .insert(movement.attack_movements(attacking.direction, attacking.heading));
+                }
+                AttackEvent::StartSwing(swing) => {
+                    if let Some(wielding) = wielding
+                        && let Ok(weapon_transform) = weapon_q.get(wielding.0)
+                    {
+                        let origin = transform
+                            .translation
+                            .xy()
+                            .extend(weapon_transform.translation.z);
+
+                        commands.entity(wielding.0).insert(swing.swings(
+                            *weapon_transform,
+                            origin,
+                            attacking.direction,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn spawn_hitbox(
+    commands: &mut Commands,
+    attacker: Entity,
+    attacker_transform: &Transform,
+    hitbox: &HitboxDefinition,
+    direction: Vec2,
+    potion: Option<PotionDefinition>,
+    target: Option<Vec2>,
+    charge: ChargeScale,
+    layer: CollisionLayers,
+    z_layer: f32,
+) {
+    let mut new_transform =
+        Transform::from_translation((direction * hitbox.range * charge.range).extend(z_layer));
+    let movement = hitbox.movement(direction);
+
+    if movement.is_some() || hitbox.thrown.is_some() {
+        new_transform.translation += attacker_transform.translation;
+    }
+
+    // A thrown hitbox stays upright, so its sprite can be raised by the height of the arc.
+    if hitbox.thrown.is_none() {
+        new_transform.rotation = Quat::from_rotation_arc(Vec3::Y, direction.extend(0.));
+    }
+
+    let mut hitbox_commands = commands.spawn((
+        hitbox.shape.scaled(charge.size).collider(),
+        Sensor,
+        CollidingEntities::default(),
+        HitRegistry::new(hitbox.hits),
+        new_transform,
+        AttackHitBoxTimer(Timer::new(hitbox.duration(), TimerMode::Once)),
+        Hitbox {
+            attacker,
+            damage: (f32::from(hitbox.damage) * charge.damage).round() as i16,
+            damage_type: hitbox.damage_type,
+            hitstun: hitbox.hitstun(),
+            knockback: hitbox.knockback,
+        },
+        layer,
+    ));
+
+    if let Some(sprite) = &hitbox.sprite
+        && hitbox.thrown.is_none()
+    {
+        hitbox_commands.insert(sprite.sprite());
+    }
+
+    if let Some(swings) = hitbox.swings(direction) {
+        hitbox_commands.insert(swings);
+    }
+
+    // A potion decides on its own what it does to marks.
+    if let Some(potion) = potion {
+        hitbox_commands.insert(Potion(PotionDefinition {
+            radius: potion.radius * charge.size,
+            ..potion
+        }));
+    } else if let Some(marker) = &hitbox.marker {
+        match marker {
+            AttackMarker::AppliesMark(kind) => hitbox_commands.insert(AppliesMark(*kind)),
+            AttackMarker::TriggersMark(release) => hitbox_commands.insert(TriggersMark(*release)),
+        };
+    }
+
+    if hitbox.parry {
+        hitbox_commands.insert((Parries, GameCollisionLayer::parry()));
+    }
+
+    if let Some(sound) = &hitbox.sound {
+        hitbox_commands.insert(HitboxSound(sound.handle.clone_weak()));
+    }
+
+    if let Some(thrown) = &hitbox.thrown {
+        let origin = new_transform.translation.xy();
+
+        hitbox_commands
+            .insert((
+                Thrown::new(
+                    origin,
+                    thrown.landing(origin, direction, target, charge.range),
+                    thrown,
+                ),
+                CollisionEventsEnabled,
+            ))
+            .with_children(|parent| {
+                let mut sprite_commands = parent.spawn((ThrownSprite, Transform::default()));
+
+                if let Some(sprite) = &hitbox.sprite {
+                    sprite_commands.insert(sprite.sprite());
+                }
+
+                parent.spawn((
+                    Sprite::from_color(Color::BLACK.with_alpha(0.4), Vec2::new(5., 2.)),
+                    Transform::from_xyz(0., -1., -0.1),
+                    Name::new("Shadow"),
+                ));
+            });
+    } else if let Some(movement) = movement {
+        hitbox_commands.insert((
+            TransformInterpolation,
+            movement,
+            RigidBody::Kinematic,
+            CollisionEventsEnabled,
+        ));
+    } else {
+        hitbox_commands.insert(CollisionEventsEnabled);
+        let hitbox_entity = hitbox_commands.id();
+        commands.entity(attacker).add_child(hitbox_entity);
+    }
+}
+
+pub(super) fn attacking_movement(
+    vel_q: Query<(Entity, &mut LinearVelocity, &mut AttackMovements)>,
+    mut commands: Commands,
+    time: Res<Time<Virtual>>,
+) {
+    let delta = time.delta();
+
+    for (entity, mut lin_vel, mut attack_movement) in vel_q {
+        attack_movement.stopwatch.tick(delta);
+        let elapsed = attack_movement.stopwatch.elapsed();
+
+        let Some((start, movement)) = attack_movement.movements.last() else {
+            commands.entity(entity).remove::<AttackMovements>();
+            continue;
+        };
+
+        if start >= &elapsed {
+            continue;
+        }
+
+        if movement.duration >= elapsed {
+            let t = (attack_movement.stopwatch.elapsed_secs() / movement.duration.as_secs_f32())
+                .clamp(0., 1.);
+            lin_vel.set_if_neq(LinearVelocity(
+                movement
+                    .from_to
+                    .0
+                    .lerp(movement.from_to.1, movement.easing.sample_clamped(t))
+                    * movement.speed,
+            ));
+        } else {
+            attack_movement.movements.pop();
+        }
+    }
+}
+
+pub(super) fn animate_swing(
+    swing_q: Query<(Entity, &mut Transform, &mut Swings)>,
+    mut commands: Commands,
+    time: Res<Time<Virtual>>,
+) {
+    let delta = time.delta();
+
+    for (entity, mut transform, mut swinging) in swing_q {
+        swinging.stopwatch.tick(delta);
+
+        let elapsed = swinging.stopwatch.elapsed();
+
+        let Some((start, swing)) = swinging.swings.last() else {
+            commands.entity(entity).remove::<Swings>();
+            continue;
+        };
+
+        if start >= &elapsed {
+            continue;
+        }
+
+        if swing.duration >= elapsed {
+            let t = (elapsed.as_secs_f32() / swing.duration.as_secs_f32()).clamp(0., 1.);
+            transform.translation = swing
+                .from
+                .translation
+                .lerp(swing.to.translation, swing.easing.sample(t).unwrap());
+
+            transform.scale = swing
+                .from
+                .scale
+                .lerp(swing.to.scale, swing.easing.sample(t).unwrap());
+        } else {
+            swinging.swings.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn once_hits_each_target_once() {
+        let mut registry = HitRegistry::new(HitPolicy::Once);
+        let (first, second) = (Entity::from_raw(1), Entity::from_raw(2));
+
+        assert!(registry.register(first));
+        assert!(!registry.register(first));
+        assert!(registry.register(second));
+        assert!(!registry.exhausted());
+        assert_eq!(registry.just_hit(), &[first, second]);
+    }
+
+    #[test]
+    fn interval_hits_again_after_the_interval() {
+        let mut registry = HitRegistry::new(HitPolicy::Interval(0.5));
+        let target = Entity::from_raw(1);
+
+        assert!(registry.register(target));
+        registry.stopwatch.tick(Duration::from_millis(400));
+        assert!(!registry.register(target));
+        registry.stopwatch.tick(Duration::from_millis(100));
+        assert!(registry.register(target));
+    }
+
+    #[test]
+    fn pierce_stops_after_its_targets() {
+        let mut registry = HitRegistry::new(HitPolicy::Pierce(2));
+
+        assert!(registry.register(Entity::from_raw(1)));
+        assert!(!registry.exhausted());
+        assert!(registry.register(Entity::from_raw(2)));
+        assert!(registry.exhausted());
+        assert!(!registry.register(Entity::from_raw(3)));
+    }
+}
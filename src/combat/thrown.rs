@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use avian2d::prelude::ColliderDisabled;
+use bevy::{prelude::*, time::Stopwatch};
+
+use crate::combat::definition::ThrownDefinition;
+
+const MIN_FLIGHT_TIME: f32 = 0.15;
+
+#[derive(Component, Reflect)]
+#[require(ColliderDisabled)]
+pub(crate) struct Thrown {
+    origin: Vec2,
+    target: Vec2,
+    height: f32,
+    angular_speed: f32,
+    duration: Duration,
+    stopwatch: Stopwatch,
+}
+
+#[derive(Component, Reflect)]
+pub(crate) struct ThrownSprite;
+
+#[derive(Event)]
+pub(crate) struct Landed {
+    pub(crate) position: Vec2,
+}
+
+impl Thrown {
+    pub(crate) fn new(origin: Vec2, target: Vec2, definition: &ThrownDefinition) -> Self {
+        let distance = origin.distance(target);
+
+        Self {
+            origin,
+            target,
+            // Short throws arc lower, so they don't hang in the air.
+            height: definition.height * (distance / definition.max_range).clamp(0.3, 1.),
+            angular_speed: definition.angular_speed,
+            duration: Duration::from_secs_f32((distance / definition.speed).max(MIN_FLIGHT_TIME)),
+            stopwatch: Stopwatch::new(),
+        }
+    }
+}
+
+pub(crate) fn move_thrown(
+    mut commands: Commands,
+    thrown_q: Query<(Entity, &mut Thrown, &mut Transform, &Children)>,
+    mut sprite_q: Query<&mut Transform, (With<ThrownSprite>, Without<Thrown>)>,
+    time: Res<Time<Virtual>>,
+) {
+    for (entity, mut thrown, mut transform, children) in thrown_q {
+        thrown.stopwatch.tick(time.delta());
+
+        let t = (thrown.stopwatch.elapsed_secs() / thrown.duration.as_secs_f32()).clamp(0., 1.);
+        let position = thrown.origin.lerp(thrown.target, t);
+        transform.translation = position.extend(transform.translation.z);
+
+        // A parabola that starts and ends on the ground.
+        let height = 4. * thrown.height * t * (1. - t);
+
+        for child in children {
+            if let Ok(mut sprite_transform) = sprite_q.get_mut(*child) {
+                sprite_transform.translation.y = height;
+                sprite_transform.rotate_z(thrown.angular_speed * time.delta_secs());
+            }
+        }
+
+        if t >= 1. {
+            commands
+                .entity(entity)
+                .remove::<(Thrown, ColliderDisabled)>()
+                .trigger(Landed { position });
+        }
+    }
+}
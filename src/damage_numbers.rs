@@ -0,0 +1,201 @@
+use std::time::Duration;
+
+use bevy::{
+    color::palettes::css::{CHARTREUSE, LIGHT_SKY_BLUE, ORANGE_RED, VIOLET, WHITE},
+    prelude::*,
+};
+use rand::Rng;
+
+use crate::{
+    AssetState, GameState, ZLayer,
+    combat::{DamageType, Damaged},
+    settings::Settings,
+};
+
+const POOL_SIZE: usize = 64;
+
+const FONT_SIZE: f32 = 40.;
+const TEXT_SCALE: f32 = 0.15;
+const CRITICAL_SCALE: f32 = 1.6;
+
+const RISE: f32 = 12.;
+const LIFETIME: f32 = 0.8;
+
+pub(super) struct DamageNumbersPlugin;
+
+impl Plugin for DamageNumbersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<FloatingText>()
+            .add_systems(OnEnter(AssetState::Loaded), spawn_pool)
+            .add_systems(
+                Update,
+                (
+                    show_damage_numbers.run_if(|settings: Res<Settings>| settings.damage_numbers),
+                    show_floating_text,
+                    animate_damage_numbers,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Running)),
+            );
+
+        #[cfg(debug_assertions)]
+        app.register_type::<DamageNumber>();
+    }
+}
+
+#[derive(Component, Reflect)]
+struct DamageNumber {
+    origin: Vec2,
+    scale: f32,
+    timer: Timer,
+}
+
+#[derive(Resource)]
+struct DamageNumberPool {
+    entities: Vec<Entity>,
+    next: usize,
+}
+
+#[derive(Event)]
+pub(crate) struct FloatingText {
+    pub(crate) text: String,
+    pub(crate) color: Color,
+    pub(crate) position: Vec2,
+}
+
+impl DamageNumberPool {
+    fn take(&mut self) -> Entity {
+        let entity = self.entities[self.next];
+        self.next = (self.next + 1) % self.entities.len();
+        entity
+    }
+}
+
+impl DamageType {
+    fn color(self) -> Color {
+        match self {
+            DamageType::Acid => Color::from(CHARTREUSE),
+            DamageType::Arcane => Color::from(VIOLET),
+            DamageType::Fire => Color::from(ORANGE_RED),
+            DamageType::Frost => Color::from(LIGHT_SKY_BLUE),
+            DamageType::Physical => Color::from(WHITE),
+        }
+    }
+}
+
+fn spawn_pool(mut commands: Commands) {
+    let entities = (0..POOL_SIZE)
+        .map(|_| {
+            // Idle until a damage instance resets it.
+            let mut timer = Timer::from_seconds(LIFETIME, TimerMode::Once);
+            timer.tick(Duration::from_secs_f32(LIFETIME));
+
+            commands
+                .spawn((
+                    DamageNumber {
+                        origin: Vec2::ZERO,
+                        scale: TEXT_SCALE,
+                        timer,
+                    },
+                    Text2d::default(),
+                    TextFont::from_font_size(FONT_SIZE),
+                    TextColor::WHITE,
+                    Transform::from_scale(Vec3::splat(TEXT_SCALE)),
+                    Visibility::Hidden,
+                    Name::new("Damage Number"),
+                ))
+                .id()
+        })
+        .collect();
+
+    commands.insert_resource(DamageNumberPool { entities, next: 0 });
+}
+
+fn show_damage_numbers(
+    mut damaged: EventReader<Damaged>,
+    mut pool: ResMut<DamageNumberPool>,
+    mut number_q: Query<(
+        &mut DamageNumber,
+        &mut Text2d,
+        &mut TextColor,
+        &mut Visibility,
+    )>,
+) {
+    let mut rng = rand::rng();
+
+    for event in damaged.read() {
+        let Ok((mut number, mut text, mut color, mut visibility)) = number_q.get_mut(pool.take())
+        else {
+            continue;
+        };
+
+        // Spread the numbers a little, so simultaneous hits on the same target stay readable.
+        number.origin = event.position + Vec2::new(rng.random_range(-4.0..4.0), 0.);
+        number.scale = if event.critical {
+            TEXT_SCALE * CRITICAL_SCALE
+        } else {
+            TEXT_SCALE
+        };
+        number.timer.reset();
+
+        text.0 = event.amount.to_string();
+        color.0 = event.damage_type.color();
+        *visibility = Visibility::Visible;
+    }
+}
+
+fn show_floating_text(
+    mut floating_text: EventReader<FloatingText>,
+    mut pool: ResMut<DamageNumberPool>,
+    mut number_q: Query<(
+        &mut DamageNumber,
+        &mut Text2d,
+        &mut TextColor,
+        &mut Visibility,
+    )>,
+) {
+    for event in floating_text.read() {
+        let Ok((mut number, mut text, mut color, mut visibility)) = number_q.get_mut(pool.take())
+        else {
+            continue;
+        };
+
+        number.origin = event.position;
+        number.scale = TEXT_SCALE;
+        number.timer.reset();
+
+        text.0.clone_from(&event.text);
+        color.0 = event.color;
+        *visibility = Visibility::Visible;
+    }
+}
+
+fn animate_damage_numbers(
+    number_q: Query<(
+        &mut DamageNumber,
+        &mut Transform,
+        &mut TextColor,
+        &mut Visibility,
+    )>,
+    time: Res<Time<Virtual>>,
+) {
+    for (mut number, mut transform, mut color, mut visibility) in number_q {
+        if number.timer.finished() {
+            continue;
+        }
+
+        let t = number.timer.tick(time.delta()).fraction();
+        let rise = RISE * EaseFunction::QuadraticOut.sample_clamped(t);
+
+        transform.translation =
+            (number.origin + Vec2::Y * rise).extend(ZLayer::DamageNumbers.z_layer());
+        transform.scale = Vec3::splat(number.scale);
+        color
+            .0
+            .set_alpha(1. - EaseFunction::QuadraticIn.sample_clamped(t));
+
+        if number.timer.finished() {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
@@ -1,27 +1,32 @@
-use std::time::Duration;
-
 use avian2d::prelude::*;
-use bevy::{color::palettes::css::RED, prelude::*, sprite::Anchor, time::Stopwatch};
-use bevy_seedling::{prelude::Volume, sample::SamplePlayer};
+use bevy::{color::palettes::css::RED, prelude::*, sprite::Anchor};
 use rand::Rng;
 
 use crate::AssetState;
-use crate::combat::AttackMovement;
-use crate::combat::Attacking;
-use crate::combat::Swing;
-use crate::combat::Swings;
+use crate::combat::{AttackCooldowns, AttackRequested, Resistances};
 use crate::movement::Moving;
+use crate::pickup::{DropTable, PickupKind};
+use crate::player::Reagent;
+use crate::status::StatusEffects;
 use crate::{
-    AttackMovements, AudioAssets, GameCollisionLayer, GameState, Health, HealthBar, Rooted,
-    SpriteAssets, ZLayer, player::Player,
+    AttackAssets, GameCollisionLayer, GameState, Health, HealthBar, Rooted, SpriteAssets, ZLayer,
+    despawn_all, player::Player,
 };
 
 pub(super) struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(enemy_attack)
-            .add_systems(OnEnter(AssetState::Loaded), startup)
+        app.add_systems(OnEnter(AssetState::Loaded), startup)
+            .add_systems(
+                OnExit(GameState::GameOver),
+                (
+                    despawn_all::<Enemy>,
+                    despawn_all::<Following>,
+                    reset_spawn_timer,
+                    startup,
+                ),
+            )
             .add_systems(
                 Update,
                 (move_enemies, spawn_enemies, move_followers).run_if(in_state(GameState::Running)),
@@ -31,15 +36,27 @@ impl Plugin for EnemyPlugin {
         #[cfg(debug_assertions)]
         app.register_type::<Enemy>()
             .register_type::<FollowedBy>()
-            .register_type::<Following>();
+            .register_type::<Following>()
+            .register_type::<Spits>();
     }
 }
 
+const SPIT_RANGE: f32 = 150.;
+
+const BITE_RANGE: f32 = 50.;
+
+const SPITTER_CHANCE: f64 = 0.3;
+
 #[derive(Resource, Reflect, DerefMut, Deref)]
 struct SpawnTimer(Timer);
 
+#[derive(Component, Reflect)]
+struct Spits;
+
 #[derive(Component, Reflect)]
 #[require(
+    AttackCooldowns,
+    StatusEffects,
     Moving,
     RigidBody::Kinematic,
     Collider::circle(30.),
@@ -56,6 +73,8 @@ impl Enemy {
         speed: f32,
         health: i16,
         collider_size: f32,
+        resistances: Resistances,
+        drop_table: DropTable,
         name: String,
         sprite_handle: Handle<Image>,
         mesh: Mesh2d,
@@ -63,6 +82,8 @@ impl Enemy {
     ) -> impl Bundle {
         (
             Self { speed },
+            resistances,
+            drop_table,
             Health {
                 current: health,
                 max: health,
@@ -80,6 +101,7 @@ impl Enemy {
                     GameCollisionLayer::Player,
                     GameCollisionLayer::PlayerAttack,
                     GameCollisionLayer::Mark,
+                    GameCollisionLayer::Hazard,
                 ]],
             ),
             Collider::circle(collider_size),
@@ -111,9 +133,25 @@ impl Following {
 }
 
 #[derive(Component, Debug, Reflect)]
-#[relationship_target(relationship = Following)]
+#[relationship_target(relationship = Following, linked_spawn)]
 pub(super) struct FollowedBy(Vec<Entity>);
 
+fn training_dummy_drops() -> DropTable {
+    DropTable(vec![
+        (0.6, PickupKind::Currency(5)),
+        (0.2, PickupKind::Health(10)),
+        (0.3, PickupKind::Reagent(Reagent::Bile)),
+        (0.15, PickupKind::Reagent(Reagent::Brimstone)),
+        (0.15, PickupKind::Reagent(Reagent::Frostleaf)),
+        (0.1, PickupKind::Reagent(Reagent::Moonsalt)),
+        (0.05, PickupKind::Reagent(Reagent::Pitch)),
+        (0.05, PickupKind::Reagent(Reagent::Bloodmoss)),
+        (0.05, PickupKind::Reagent(Reagent::Quicksilver)),
+        (0.05, PickupKind::Reagent(Reagent::Lodestone)),
+        (0.05, PickupKind::Reagent(Reagent::Amber)),
+    ])
+}
+
 fn startup(
     mut commands: Commands,
     sprite_assets: Res<SpriteAssets>,
@@ -125,6 +163,12 @@ fn startup(
         30.,
         30,
         8.,
+        Resistances {
+            fire: 1.5,
+            frost: 0.5,
+            ..default()
+        },
+        training_dummy_drops(),
         String::from("Training Dummy"),
         sprite_assets.enemy.clone_weak(),
         Mesh2d(meshes.add(Rectangle::new(25., 2.5))),
@@ -132,103 +176,58 @@ fn startup(
     ));
 }
 
-fn enemy_attack(
-    trigger: Trigger<OnCollisionStart>,
-    mut commands: Commands,
-    mut player: Single<(Entity, &mut Health), With<Player>>,
-) {
-    if player.0 == (trigger.collider) {
-        player.1.current -= 10;
-        commands.entity(trigger.target()).insert(ColliderDisabled);
-    }
+fn reset_spawn_timer(mut timer: ResMut<SpawnTimer>) {
+    timer.0.reset();
 }
 
 fn move_enemies(
     mut commands: Commands,
     enemy_q: Query<
-        (Entity, &mut LinearVelocity, &Transform, &Enemy),
+        (
+            Entity,
+            &mut LinearVelocity,
+            &Transform,
+            &Enemy,
+            &StatusEffects,
+            &AttackCooldowns,
+            Has<Spits>,
+        ),
         (With<Moving>, Without<Rooted>),
     >,
     player: Single<&Transform, With<Player>>,
-    sprite_assets: Res<SpriteAssets>,
-    audio_assets: Res<AudioAssets>,
+    attack_assets: Res<AttackAssets>,
 ) {
-    for (enemy_entity, mut vel, enemy_transform, enemy) in enemy_q {
+    for (enemy_entity, mut vel, enemy_transform, enemy, statuses, cooldowns, spits) in enemy_q {
         let normalized_direction_vector =
             (player.translation.xy() - enemy_transform.translation.xy()).normalize_or_zero();
+        let distance = enemy_transform.translation.distance(player.translation);
 
-        if enemy_transform.translation.distance(player.translation) < 50. {
-            let mut new_transform =
-                Transform::from_translation((normalized_direction_vector * 80.).extend(0.));
-            new_transform.rotation =
-                Quat::from_rotation_arc(Vec3::Y, normalized_direction_vector.extend(0.));
-
-            commands.spawn(
-                SamplePlayer::new(audio_assets.bite_swing.clone_weak())
-                    .with_volume(Volume::Linear(0.5)),
-            );
+        let attack = if distance < BITE_RANGE {
+            Some(&attack_assets.bite)
+        } else if spits && distance < SPIT_RANGE && cooldowns.ready(attack_assets.spit.id()) {
+            Some(&attack_assets.spit)
+        } else {
+            None
+        };
 
-            let rooted_duration = Duration::from_secs_f32(1.25);
-            commands.entity(enemy_entity).remove::<Moving>().insert((
-                Attacking {
-                    swing_sound: Some((
-                        Duration::from_secs_f32(0.25),
-                        audio_assets.bite_swing.clone_weak(),
-                    )),
-                    hitbox_movement: Vec::new(),
-                    target: normalized_direction_vector,
-                    spawn_hitbox: vec![Duration::from_secs_f32(0.35)],
-                    stopwatch: Stopwatch::new(),
-                    range: 15.,
-                    hitbox: vec![Collider::rectangle(15., 15.)],
-                    hitbox_duration: vec![Duration::from_secs_f32(0.25)],
-                    marker: None,
-                    sprite: Some(Sprite {
-                        image: sprite_assets.bite.clone_weak(),
-                        ..default()
-                    }),
-                    hitbox_sound: vec![audio_assets.bite_impact.clone_weak()],
-                    swings: Some(Swings {
-                        swings: vec![(
-                            Duration::ZERO,
-                            Swing {
-                                from: Transform::from_translation(
-                                    (normalized_direction_vector * 15.).extend(0.),
-                                ),
-                                to: Transform::from_translation(
-                                    (normalized_direction_vector * 15.).extend(0.),
-                                )
-                                .with_scale(Vec3::new(1., 0.1, 1.)),
-                                duration: Duration::from_secs_f32(0.25),
-                                easing: EaseFunction::BackIn,
-                            },
-                        )],
-                        stopwatch: Stopwatch::new(),
-                    }),
-                },
-                AttackMovements {
-                    movements: vec![(
-                        Duration::from_secs_f32(0.25),
-                        AttackMovement {
-                            easing: EaseFunction::Linear,
-                            speed: 250.,
-                            from_to: (normalized_direction_vector, Vec2::ZERO),
-                            duration: Duration::from_secs_f32(1.5),
-                        },
-                    )],
-                    stopwatch: Stopwatch::new(),
-                },
-                Rooted {
-                    duration: rooted_duration,
-                    stopwatch: Stopwatch::new(),
-                },
-            ));
+        if let Some(attack) = attack {
+            commands.trigger(AttackRequested {
+                attacker: enemy_entity,
+                attack: attack.clone_weak(),
+                charge: 0.,
+                direction: normalized_direction_vector,
+                potion: None,
+                potion_slot: None,
+                target: None,
+            });
             vel.set_if_neq(LinearVelocity::ZERO);
 
             continue;
         }
 
-        vel.set_if_neq(LinearVelocity(normalized_direction_vector * enemy.speed));
+        vel.set_if_neq(LinearVelocity(
+            normalized_direction_vector * enemy.speed * statuses.speed_multiplier(),
+        ));
     }
 }
 
@@ -245,7 +244,7 @@ fn spawn_enemies(
         let mut rng = rand::rng();
 
         for _ in 1..=rng.random_range(2..5) {
-            commands.spawn((Enemy::bundle(
+            let mut enemy_commands = commands.spawn((Enemy::bundle(
                 Vec3::new(
                     rng.random_range((player.translation.x - 500.)..(player.translation.x + 500.)),
                     rng.random_range((player.translation.y - 500.)..(player.translation.y + 500.)),
@@ -254,11 +253,21 @@ fn spawn_enemies(
                 30.,
                 30,
                 8.,
+                Resistances {
+                    fire: 1.5,
+                    frost: 0.5,
+                    ..default()
+                },
+                training_dummy_drops(),
                 String::from("Training Dummy"),
                 sprite_assets.enemy.clone_weak(),
                 Mesh2d(meshes.add(Rectangle::new(25., 2.5))),
                 materials.add(Color::from(RED)),
             ),));
+
+            if rng.random_bool(SPITTER_CHANCE) {
+                enemy_commands.insert((Spits, Name::new("Spitting Dummy")));
+            }
         }
     }
 }
@@ -0,0 +1,170 @@
+use bevy::{color::palettes::css::GOLD, prelude::*, time::Stopwatch};
+use bevy_enhanced_input::prelude::*;
+
+use crate::{
+    GameState,
+    combat::{Damaged, Health, Killed, apply_damage, despawn_killed},
+    enemy::Enemy,
+    player::Player,
+};
+
+pub(super) struct GameOverPlugin;
+
+impl Plugin for GameOverPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunStats>()
+            .add_input_context::<GameOverInput>()
+            .add_observer(binding)
+            .add_observer(restart_action)
+            .add_systems(
+                Update,
+                (
+                    tick_run_time,
+                    track_run_stats.after(apply_damage).before(despawn_killed),
+                )
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (pause_time, spawn_game_over_screen),
+            )
+            .add_systems(OnExit(GameState::GameOver), (unpause_time, reset_run_stats));
+
+        #[cfg(debug_assertions)]
+        app.register_type::<RunStats>();
+    }
+}
+
+#[derive(Component, Reflect)]
+struct GameOverScreen;
+
+#[derive(InputContext)]
+struct GameOverInput;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct Restart;
+
+#[derive(Resource, Reflect, Default)]
+struct RunStats {
+    damage_dealt: u32,
+    kills: u32,
+    score: u32,
+    time: Stopwatch,
+}
+
+fn tick_run_time(mut stats: ResMut<RunStats>, time: Res<Time<Virtual>>) {
+    stats.time.tick(time.delta());
+}
+
+fn track_run_stats(
+    mut damaged: EventReader<Damaged>,
+    mut killed: EventReader<Killed>,
+    mut stats: ResMut<RunStats>,
+    mut next_state: ResMut<NextState<GameState>>,
+    player: Single<Entity, With<Player>>,
+    enemy_q: Query<&Health, With<Enemy>>,
+) {
+    for event in damaged.read() {
+        if event.source == *player && event.target != *player {
+            stats.damage_dealt += u32::from(event.amount.unsigned_abs());
+        }
+    }
+
+    for event in killed.read() {
+        if event.target == *player {
+            next_state.set(GameState::GameOver);
+        } else if event.source == *player
+            && let Ok(health) = enemy_q.get(event.target)
+        {
+            stats.kills += 1;
+            stats.score += u32::from(health.max.unsigned_abs());
+        }
+    }
+}
+
+fn pause_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
+fn unpause_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
+
+fn reset_run_stats(mut stats: ResMut<RunStats>) {
+    *stats = RunStats::default();
+}
+
+fn spawn_game_over_screen(mut commands: Commands, stats: Res<RunStats>) {
+    let seconds = stats.time.elapsed().as_secs();
+
+    let lines = [
+        format!("Survived {}:{:02}", seconds / 60, seconds % 60),
+        format!("Kills {}", stats.kills),
+        format!("Damage dealt {}", stats.damage_dealt),
+        format!("Score {}", stats.score),
+    ];
+
+    commands
+        .spawn((
+            GameOverScreen,
+            Actions::<GameOverInput>::default(),
+            Name::new("Game Over Screen"),
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(12.),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.6)),
+            StateScoped(GameState::GameOver),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Game Over"),
+                TextFont::from_font_size(64.),
+                TextColor(Color::from(GOLD)),
+            ));
+
+            for line in lines {
+                parent.spawn((Text::new(line), TextFont::from_font_size(28.)));
+            }
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        margin: UiRect::top(Val::Px(24.)),
+                        padding: UiRect::axes(Val::Px(24.), Val::Px(12.)),
+                        border: UiRect::all(Val::Px(2.)),
+                        ..default()
+                    },
+                    BorderColor(Color::WHITE),
+                    children![(Text::new("Restart"), TextFont::from_font_size(32.))],
+                ))
+                .observe(restart);
+        });
+}
+
+fn binding(
+    trigger: Trigger<Binding<GameOverInput>>,
+    mut screens: Query<&mut Actions<GameOverInput>>,
+) {
+    let mut actions = screens.get_mut(trigger.target()).unwrap();
+
+    actions
+        .bind::<Restart>()
+        .to((KeyCode::Enter, KeyCode::KeyR))
+        .with_conditions(Press::default());
+}
+
+fn restart(_: Trigger<Pointer<Click>>, mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Running);
+}
+
+fn restart_action(_: Trigger<Fired<Restart>>, mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Running);
+}
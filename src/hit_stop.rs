@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+
+use crate::{
+    GameState,
+    combat::{DamageKind, Damaged},
+    player::Player,
+    settings::Settings,
+};
+
+const HIT_STOP_SPEED: f32 = 0.05;
+
+const HIT_DURATION: f32 = 0.06;
+const DETONATION_DURATION: f32 = 0.12;
+
+pub(super) struct HitStopPlugin;
+
+impl Plugin for HitStopPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HitStop>()
+            .add_systems(
+                Update,
+                (start_hit_stop, tick_hit_stop)
+                    .chain()
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(OnEnter(GameState::GameOver), end_hit_stop);
+    }
+}
+
+#[derive(Resource, Default)]
+struct HitStop {
+    remaining: f32,
+}
+
+fn start_hit_stop(
+    mut damaged: EventReader<Damaged>,
+    mut hit_stop: ResMut<HitStop>,
+    player: Single<Entity, With<Player>>,
+    settings: Res<Settings>,
+) {
+    for event in damaged.read() {
+        if !settings.hit_stop || event.source != *player {
+            continue;
+        }
+
+        let duration = match event.kind {
+            DamageKind::Hit => HIT_DURATION,
+            DamageKind::MarkDetonation => DETONATION_DURATION,
+            _ => continue,
+        };
+
+        // Hits landing together extend the stop instead of stacking it.
+        hit_stop.remaining = hit_stop
+            .remaining
+            .max(duration * settings.hit_stop_intensity);
+    }
+}
+
+fn tick_hit_stop(
+    mut hit_stop: ResMut<HitStop>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+) {
+    if hit_stop.remaining <= 0. {
+        return;
+    }
+
+    hit_stop.remaining -= real_time.delta_secs();
+
+    let speed = if hit_stop.remaining > 0. {
+        HIT_STOP_SPEED
+    } else {
+        1.
+    };
+
+    if virtual_time.relative_speed() != speed {
+        virtual_time.set_relative_speed(speed);
+    }
+}
+
+fn end_hit_stop(mut hit_stop: ResMut<HitStop>, mut virtual_time: ResMut<Time<Virtual>>) {
+    hit_stop.remaining = 0.;
+    virtual_time.set_relative_speed(1.);
+}
@@ -2,9 +2,15 @@ mod assets;
 mod audio;
 mod camera;
 mod combat;
+mod damage_numbers;
 mod enemy;
+mod game_over;
+mod hit_stop;
 mod movement;
+mod pickup;
 mod player;
+mod settings;
+mod status;
 mod touch;
 
 use avian2d::prelude::*;
@@ -19,28 +25,38 @@ use bevy_seedling::SeedlingPlugin;
 use virtual_joystick::VirtualJoystickPlugin;
 
 use crate::{
-    assets::{AudioAssets, ParticleAssets, SpriteAssets},
-    audio::spawn_collision_sound,
+    assets::{AttackAssets, AudioAssets, ParticleAssets, SpriteAssets},
+    audio::{spawn_collision_sound, spawn_landing_sound},
     camera::CameraPlugin,
     combat::{
-        AttackMovements, Health, HealthBar, animate_swing, attacking_movement, tick_attack_timer,
-        tick_hitbox_timer,
+        AttackDefinition, AttackDefinitionLoader, AttackMovements, DamageEvent, Damaged, Essence,
+        EssenceBar, Health, HealthBar, Hitbox, Killed, animate_swing, apply_damage,
+        attacking_movement, despawn_killed, hitbox_hit, move_thrown, regenerate_essence,
+        replay_buffered_attacks, request_attack, tick_attack_cooldowns, tick_attack_timer,
+        tick_combos, tick_empowered, tick_hitbox_timer, tick_invulnerable,
     },
+    damage_numbers::DamageNumbersPlugin,
     enemy::{Enemy, EnemyPlugin},
-    movement::{Rooted, kinematic_collisions, tick_rooted},
+    game_over::GameOverPlugin,
+    hit_stop::HitStopPlugin,
+    movement::{Rooted, kinematic_collisions, tick_rooted, tick_stunned},
+    pickup::PickupPlugin,
     player::{AppliesMark, AttackMarker, JoystickID, PlayerPlugin, TriggersMark},
+    settings::SettingsPlugin,
+    status::StatusPlugin,
     touch::touch_interface,
 };
 
 #[cfg(debug_assertions)]
 use crate::{
     audio::HitboxSound,
-    combat::{AttackHitBoxTimer, Swings},
+    combat::{
+        AttackHitBoxTimer, DamageModifiers, Empowered, HitRegistry, Invulnerable,
+        InvulnerableOnHit, Parries, Resistances, Swings, Thrown, ThrownSprite, WieldedBy, Wielding,
+    },
+    movement::Stunned,
 };
 
-#[derive(Component, Reflect)]
-struct GameOverScreen;
-
 #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
 enum AssetState {
     Loaded,
@@ -52,6 +68,7 @@ enum AssetState {
 #[source(AssetState = AssetState::Loaded)]
 #[states(scoped_entities)]
 enum GameState {
+    GameOver,
     Paused,
     #[default]
     Running,
@@ -66,11 +83,13 @@ enum CursorState {
 }
 
 enum ZLayer {
+    DamageNumbers,
     Effects,
     Enemies,
     EnemyWeapon,
     HealthBar,
     Map,
+    Pickups,
     Player,
     PlayerWeapon,
 }
@@ -78,11 +97,13 @@ enum ZLayer {
 impl ZLayer {
     fn z_layer(&self) -> f32 {
         match self {
+            ZLayer::DamageNumbers => 11.,
             ZLayer::Effects => 10.,
             ZLayer::Enemies => 2.,
             ZLayer::EnemyWeapon => 1.,
             ZLayer::HealthBar => 1.,
             ZLayer::Map => 0.,
+            ZLayer::Pickups => 1.5,
             ZLayer::Player => 3.,
             ZLayer::PlayerWeapon => 3.5,
         }
@@ -102,20 +123,38 @@ enum GameCollisionLayer {
     Default,
     Enemy,
     EnemyAttack,
+    Hazard,
     Mark,
+    Parry,
+    Pickup,
     Player,
     PlayerAttack,
 }
 
 impl GameCollisionLayer {
     fn enemy_attack() -> CollisionLayers {
-        CollisionLayers::new(GameCollisionLayer::EnemyAttack, GameCollisionLayer::Player)
+        CollisionLayers::new(
+            GameCollisionLayer::EnemyAttack,
+            [GameCollisionLayer::Parry, GameCollisionLayer::Player],
+        )
+    }
+
+    fn hazard() -> CollisionLayers {
+        CollisionLayers::new(GameCollisionLayer::Hazard, GameCollisionLayer::Enemy)
     }
 
     fn mark() -> CollisionLayers {
         CollisionLayers::new(GameCollisionLayer::Mark, GameCollisionLayer::Enemy)
     }
 
+    fn parry() -> CollisionLayers {
+        CollisionLayers::new(GameCollisionLayer::Parry, GameCollisionLayer::EnemyAttack)
+    }
+
+    fn pickup() -> CollisionLayers {
+        CollisionLayers::new(GameCollisionLayer::Pickup, GameCollisionLayer::Player)
+    }
+
     fn player_attack() -> CollisionLayers {
         CollisionLayers::new(GameCollisionLayer::PlayerAttack, GameCollisionLayer::Enemy)
     }
@@ -148,34 +187,62 @@ fn main() -> AppExit {
         PhysicsPlugins::default().with_length_unit(2.5),
         PhysicsPickingPlugin,
     ))
+    .init_asset::<AttackDefinition>()
+    .init_asset_loader::<AttackDefinitionLoader>()
     .init_state::<AssetState>()
     .init_state::<CursorState>()
     .add_loading_state(
         LoadingState::new(AssetState::Loading)
             .continue_to_state(AssetState::Loaded)
+            .load_collection::<AttackAssets>()
             .load_collection::<SpriteAssets>()
             .load_collection::<ParticleAssets>()
             .load_collection::<AudioAssets>(),
     )
     .add_sub_state::<GameState>()
     // My plugins.
-    .add_plugins((PlayerPlugin, EnemyPlugin, CameraPlugin))
+    .add_plugins((
+        PlayerPlugin,
+        EnemyPlugin,
+        CameraPlugin,
+        PickupPlugin,
+        StatusPlugin,
+        DamageNumbersPlugin,
+        HitStopPlugin,
+        GameOverPlugin,
+        SettingsPlugin,
+    ))
     .add_input_context::<InGame>()
     .insert_resource(Gravity::ZERO)
     .add_observer(binding)
     .add_observer(pause_game)
     .add_observer(spawn_collision_sound)
+    .add_observer(spawn_landing_sound)
+    .add_observer(request_attack)
+    .add_event::<DamageEvent>()
+    .add_event::<Damaged>()
+    .add_event::<Killed>()
     .add_systems(OnEnter(AssetState::Loaded), startup)
     .add_systems(OnEnter(CursorState::Touch), touch_interface)
+    .add_systems(OnExit(GameState::GameOver), despawn_all::<Hitbox>)
     .add_systems(
         Update,
         (
             tick_hitbox_timer,
+            (hitbox_hit, apply_damage, despawn_killed).chain(),
             update_healthbar,
+            (regenerate_essence, update_essence_bar).chain(),
             kinematic_collisions,
             tick_attack_timer,
+            tick_attack_cooldowns,
+            tick_combos,
+            replay_buffered_attacks,
             attacking_movement,
+            move_thrown,
             tick_rooted,
+            tick_stunned,
+            tick_invulnerable,
+            tick_empowered,
             check_input_state,
             animate_swing,
         )
@@ -191,12 +258,27 @@ fn main() -> AppExit {
         WorldInspectorPlugin::new(),
     ))
     .register_type::<AttackHitBoxTimer>()
+    .register_type::<DamageModifiers>()
     .register_type::<Health>()
     .register_type::<HealthBar>()
+    .register_type::<Empowered>()
+    .register_type::<Essence>()
+    .register_type::<EssenceBar>()
+    .register_type::<Hitbox>()
+    .register_type::<HitRegistry>()
+    .register_type::<Parries>()
+    .register_type::<Invulnerable>()
+    .register_type::<InvulnerableOnHit>()
     .register_type::<AttackMovements>()
+    .register_type::<Resistances>()
     .register_type::<Rooted>()
     .register_type::<HitboxSound>()
-    .register_type::<Swings>();
+    .register_type::<Stunned>()
+    .register_type::<Swings>()
+    .register_type::<Thrown>()
+    .register_type::<ThrownSprite>()
+    .register_type::<WieldedBy>()
+    .register_type::<Wielding>();
 
     app.run()
 }
@@ -233,8 +315,13 @@ fn binding(trigger: Trigger<Binding<InGame>>, mut players: Query<&mut Actions<In
 fn pause_game(
     _: Trigger<Fired<Pause>>,
     mut time: ResMut<Time<Virtual>>,
+    state: Res<State<GameState>>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
+    if *state.get() == GameState::GameOver {
+        return;
+    }
+
     if time.is_paused() {
         next_state.set(GameState::Running);
         time.unpause();
@@ -244,14 +331,36 @@ fn pause_game(
     }
 }
 
+fn despawn_all<T: Component>(mut commands: Commands, query: Query<Entity, With<T>>) {
+    // Some of them may be children of an entity that is despawned along with them.
+    for entity in &query {
+        commands.entity(entity).try_despawn();
+    }
+}
+
+fn update_essence_bar(
+    changed: Query<(&Essence, &Children), Changed<Essence>>,
+    mut transforms: Query<&mut Transform, With<EssenceBar>>,
+) {
+    const WIDTH: f32 = 15.;
+
+    for (essence, children) in &changed {
+        for child in children {
+            if let Ok(mut transform) = transforms.get_mut(*child) {
+                let percentage = essence.fraction();
+                transform.scale.x = percentage;
+                transform.translation.x = -WIDTH / 2. * (1. - percentage);
+            }
+        }
+    }
+}
+
 pub(crate) fn update_healthbar(
-    mut commands: Commands,
-    changed: Query<'_, '_, (Entity, &Health, &Children, Option<&Enemy>), Changed<Health>>,
+    changed: Query<'_, '_, (&Health, &Children, Option<&Enemy>), Changed<Health>>,
     mut transforms: Query<'_, '_, (&mut Visibility, &mut Transform), With<HealthBar>>,
 ) {
-    for (entity, health, children, enemy) in &changed {
+    for (health, children, enemy) in &changed {
         if health.current <= 0 {
-            commands.entity(entity).despawn();
             continue;
         }
 
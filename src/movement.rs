@@ -9,6 +9,13 @@ use bevy::{prelude::*, time::Stopwatch};
 #[derive(Component, Reflect, Default)]
 pub struct Moving;
 
+#[derive(Component, Reflect)]
+pub(super) struct Stunned {
+    pub(super) duration: Duration,
+    pub(super) knockback: Vec2,
+    pub(super) stopwatch: Stopwatch,
+}
+
 #[derive(Component, Reflect)]
 pub(super) struct Rooted {
     pub(super) duration: Duration,
@@ -16,7 +23,7 @@ pub(super) struct Rooted {
 }
 
 pub(super) fn tick_rooted(
-    rooted_q: Query<(Entity, &mut Rooted)>,
+    rooted_q: Query<(Entity, &mut Rooted), Without<Stunned>>,
     mut commands: Commands,
     time: Res<Time<Virtual>>,
 ) {
@@ -29,6 +36,27 @@ pub(super) fn tick_rooted(
         }
     }
 }
+
+pub(super) fn tick_stunned(
+    stunned_q: Query<(Entity, &mut Stunned, &mut LinearVelocity)>,
+    mut commands: Commands,
+    time: Res<Time<Virtual>>,
+) {
+    let delta = time.delta();
+    for (entity, mut stunned, mut velocity) in stunned_q {
+        stunned.stopwatch.tick(delta);
+
+        let t = (stunned.stopwatch.elapsed_secs() / stunned.duration.as_secs_f32()).clamp(0., 1.);
+        velocity.0 = stunned
+            .knockback
+            .lerp(Vec2::ZERO, EaseFunction::QuadraticOut.sample_clamped(t));
+
+        if stunned.stopwatch.elapsed() >= stunned.duration {
+            commands.entity(entity).remove::<Stunned>().insert(Moving);
+        }
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub(super) fn kinematic_collisions(
     collisions: Collisions,
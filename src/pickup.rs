@@ -0,0 +1,155 @@
+use avian2d::prelude::*;
+use bevy::{
+    color::palettes::css::{GOLD, LIME, MEDIUM_PURPLE},
+    platform::collections::HashMap,
+    prelude::*,
+};
+use rand::Rng;
+
+use crate::{
+    GameCollisionLayer, GameState, ZLayer,
+    combat::{Health, Killed, apply_damage, despawn_killed},
+    despawn_all,
+    player::{Player, Reagent},
+};
+
+const MAGNET_RANGE: f32 = 60.;
+
+const MAGNET_SPEED: f32 = 40.;
+
+pub(super) struct PickupPlugin;
+
+impl Plugin for PickupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(collect_pickup)
+            .init_resource::<Inventory>()
+            .add_systems(
+                OnExit(GameState::GameOver),
+                (despawn_all::<Pickup>, reset_inventory),
+            )
+            .add_systems(
+                Update,
+                (
+                    roll_drops.after(apply_damage).before(despawn_killed),
+                    magnetise_pickups,
+                )
+                    .run_if(in_state(GameState::Running)),
+            );
+
+        #[cfg(debug_assertions)]
+        app.register_type::<Inventory>().register_type::<Pickup>();
+    }
+}
+
+#[derive(Reflect, Clone, Copy, Debug)]
+pub(super) enum PickupKind {
+    Currency(u32),
+    Health(i16),
+    Reagent(Reagent),
+}
+
+#[derive(Component, Reflect)]
+#[require(Sensor, CollisionEventsEnabled, Collider::circle(3.))]
+pub(super) struct Pickup(PickupKind);
+
+#[derive(Component)]
+pub(super) struct DropTable(pub(super) Vec<(f32, PickupKind)>);
+
+#[derive(Resource, Reflect, Default)]
+pub(super) struct Inventory {
+    pub(super) currency: u32,
+    pub(super) reagents: HashMap<Reagent, u32>,
+}
+
+impl PickupKind {
+    fn color(self) -> Color {
+        match self {
+            PickupKind::Currency(_) => Color::from(GOLD),
+            PickupKind::Health(_) => Color::from(LIME),
+            PickupKind::Reagent(_) => Color::from(MEDIUM_PURPLE),
+        }
+    }
+}
+
+fn reset_inventory(mut inventory: ResMut<Inventory>) {
+    *inventory = Inventory::default();
+}
+
+fn roll_drops(
+    mut commands: Commands,
+    mut killed: EventReader<Killed>,
+    drop_table_q: Query<&DropTable>,
+) {
+    let mut rng = rand::rng();
+
+    for event in killed.read() {
+        let Ok(drop_table) = drop_table_q.get(event.target) else {
+            continue;
+        };
+
+        for (chance, kind) in &drop_table.0 {
+            if !rng.random_bool(f64::from(chance.clamp(0., 1.))) {
+                continue;
+            }
+
+            let scatter = Vec2::new(rng.random_range(-8.0..8.0), rng.random_range(-8.0..8.0));
+
+            commands.spawn((
+                Pickup(*kind),
+                Sprite::from_color(kind.color(), Vec2::splat(4.)),
+                GameCollisionLayer::pickup(),
+                Transform::from_translation(
+                    (event.position + scatter).extend(ZLayer::Pickups.z_layer()),
+                ),
+                Name::new("Pickup"),
+            ));
+        }
+    }
+}
+
+fn magnetise_pickups(
+    mut pickup_q: Query<&mut Transform, With<Pickup>>,
+    player: Single<&Transform, (With<Player>, Without<Pickup>)>,
+    time: Res<Time<Virtual>>,
+) {
+    let target = player.translation.xy();
+
+    for mut transform in &mut pickup_q {
+        let offset = target - transform.translation.xy();
+        let distance = offset.length();
+
+        if distance > MAGNET_RANGE || distance == 0. {
+            continue;
+        }
+
+        let speed = MAGNET_SPEED * MAGNET_RANGE / distance.max(1.);
+        let step = (speed * time.delta_secs()).min(distance);
+        transform.translation += (offset / distance * step).extend(0.);
+    }
+}
+
+fn collect_pickup(
+    trigger: Trigger<OnCollisionStart>,
+    mut commands: Commands,
+    pickup_q: Query<&Pickup>,
+    mut player_q: Query<&mut Health, With<Player>>,
+    mut inventory: ResMut<Inventory>,
+) {
+    let Ok(Pickup(kind)) = pickup_q.get(trigger.target()) else {
+        return;
+    };
+
+    let Ok(mut health) = player_q.get_mut(trigger.body.unwrap_or(trigger.collider)) else {
+        return;
+    };
+
+    match *kind {
+        PickupKind::Currency(amount) => inventory.currency += amount,
+        PickupKind::Health(amount) => {
+            health.current = (health.current + amount).min(health.max);
+        }
+        PickupKind::Reagent(reagent) => *inventory.reagents.entry(reagent).or_default() += 1,
+    }
+
+    commands.entity(trigger.target()).despawn();
+}
@@ -0,0 +1,339 @@
+use avian2d::prelude::{Collider, SpatialQuery, SpatialQueryFilter};
+use bevy::prelude::*;
+use bevy_enoki::{ParticleEffectHandle, ParticleSpawner, prelude::OneShot};
+use bevy_seedling::sample::SamplePlayer;
+
+use crate::combat::{DamageEvent, DamageKind, DamageType, Hitbox, Landed};
+use crate::{
+    AudioAssets, GameCollisionLayer, ParticleAssets, ZLayer,
+    enemy::Enemy,
+    player::{
+        belt::{BeltSlot, CHARGES_PER_BREW, PotionBelt},
+        hazard::{HazardKind, spawn_hazard},
+        mark::{Mark, MarkKind, MarkRegistry, mark_enemy},
+        release::{Release, TriggerMark},
+    },
+};
+
+pub(super) const MAX_REAGENTS: usize = 3;
+
+const BASE_RADIUS: f32 = 10.;
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Reagent {
+    Amber,
+    Bile,
+    Bloodmoss,
+    Brimstone,
+    Frostleaf,
+    Lodestone,
+    Moonsalt,
+    Pitch,
+    Quicksilver,
+}
+
+struct ReagentProperties {
+    element: Option<DamageType>,
+    potency: i16,
+    radius: f32,
+    hazard: Option<HazardKind>,
+    mark: Option<MarkKind>,
+    release: Option<Release>,
+}
+
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct PotionDefinition {
+    pub(crate) damage: i16,
+    pub(crate) damage_type: DamageType,
+    pub(crate) radius: f32,
+    pub(crate) hazard: Option<HazardKind>,
+    pub(crate) mark: Option<MarkKind>,
+    pub(crate) release: Release,
+}
+
+#[derive(Component, Reflect)]
+pub struct Potion(pub PotionDefinition);
+
+#[derive(Event)]
+pub(super) struct BrewPotion {
+    pub(super) reagents: Vec<Reagent>,
+    pub(super) slot: usize,
+}
+
+impl Reagent {
+    fn properties(self) -> ReagentProperties {
+        const INERT: ReagentProperties = ReagentProperties {
+            element: None,
+            potency: 0,
+            radius: 0.,
+            hazard: None,
+            mark: None,
+            release: None,
+        };
+
+        match self {
+            Reagent::Amber => ReagentProperties {
+                release: Some(Release::Consume {
+                    per_mark: 0.1,
+                    duration: 5.,
+                }),
+                ..INERT
+            },
+            Reagent::Bile => ReagentProperties {
+                element: Some(DamageType::Acid),
+                potency: 4,
+                radius: 10.,
+                hazard: Some(HazardKind::AcidPuddle),
+                ..INERT
+            },
+            Reagent::Bloodmoss => ReagentProperties {
+                potency: 1,
+                radius: 5.,
+                mark: Some(MarkKind::Leech),
+                ..INERT
+            },
+            Reagent::Brimstone => ReagentProperties {
+                element: Some(DamageType::Fire),
+                potency: 6,
+                radius: 15.,
+                hazard: Some(HazardKind::FirePatch),
+                ..INERT
+            },
+            Reagent::Frostleaf => ReagentProperties {
+                element: Some(DamageType::Frost),
+                potency: 3,
+                radius: 20.,
+                ..INERT
+            },
+            Reagent::Lodestone => ReagentProperties {
+                radius: 10.,
+                release: Some(Release::Implosion {
+                    pull: 200.,
+                    stun: 0.3,
+                }),
+                ..INERT
+            },
+            Reagent::Moonsalt => ReagentProperties {
+                element: Some(DamageType::Arcane),
+                potency: 2,
+                radius: 5.,
+                ..INERT
+            },
+            Reagent::Pitch => ReagentProperties {
+                radius: 5.,
+                hazard: Some(HazardKind::TarCloud),
+                ..INERT
+            },
+            Reagent::Quicksilver => ReagentProperties {
+                release: Some(Release::ChainLightning {
+                    damage: 5,
+                    hops: 3,
+                    range: 120.,
+                }),
+                ..INERT
+            },
+        }
+    }
+}
+
+pub(super) fn brew(reagents: &[Reagent]) -> PotionDefinition {
+    // Potency and radius add up, the strongest element picks the damage type and the last
+    // reagent with a hazard, mark or release wins.
+    let mut potion = PotionDefinition {
+        damage: 0,
+        damage_type: DamageType::Physical,
+        radius: BASE_RADIUS,
+        hazard: None,
+        mark: None,
+        release: Release::Detonate,
+    };
+    let mut elements: Vec<(DamageType, i16)> = Vec::new();
+
+    for properties in reagents.iter().map(|reagent| reagent.properties()) {
+        potion.damage += properties.potency;
+        potion.radius += properties.radius;
+
+        if let Some(element) = properties.element {
+            match elements
+                .iter_mut()
+                .find(|(existing, _)| *existing == element)
+            {
+                Some((_, potency)) => *potency += properties.potency,
+                None => elements.push((element, properties.potency)),
+            }
+        }
+
+        if let Some(hazard) = properties.hazard {
+            potion.hazard = Some(hazard);
+        }
+
+        if let Some(mark) = properties.mark {
+            potion.mark = Some(mark);
+        }
+
+        if let Some(release) = properties.release {
+            potion.release = release;
+        }
+    }
+
+    // `max_by_key` returns the last maximum, reversing keeps the first added element on ties.
+    if let Some((element, _)) = elements.iter().rev().max_by_key(|(_, potency)| *potency) {
+        potion.damage_type = *element;
+    }
+
+    potion
+}
+
+pub(super) fn brew_potion(trigger: Trigger<BrewPotion>, mut belt_q: Query<&mut PotionBelt>) {
+    let reagents = &trigger.reagents;
+
+    if reagents.is_empty() || reagents.len() > MAX_REAGENTS {
+        return;
+    }
+
+    if let Ok(mut belt) = belt_q.get_mut(trigger.target())
+        && let Some(slot) = belt.slots.get_mut(trigger.slot)
+    {
+        *slot = Some(BeltSlot {
+            potion: brew(reagents),
+            charges: CHARGES_PER_BREW,
+            reagents: reagents.clone(),
+        });
+    }
+}
+
+pub(super) fn shatter_potion(
+    trigger: Trigger<Landed>,
+    mut commands: Commands,
+    potion_q: Query<(&Hitbox, &Potion, &Collider)>,
+    enemy_q: Query<(Entity, &Transform, Has<Mark>), With<Enemy>>,
+    spatial_query: SpatialQuery,
+    mut damage: EventWriter<DamageEvent>,
+    registry: Res<MarkRegistry>,
+    audio_assets: Res<AudioAssets>,
+    effect_assets: Res<ParticleAssets>,
+) {
+    let Ok((hitbox, Potion(potion), collider)) = potion_q.get(trigger.target()) else {
+        return;
+    };
+
+    let center = trigger.position;
+
+    // Whatever the bottle itself came down on.
+    let hit = spatial_query
+        .shape_intersections(
+            collider,
+            center,
+            0.,
+            &SpatialQueryFilter::from_mask(GameCollisionLayer::Enemy),
+        )
+        .into_iter()
+        .find(|entity| enemy_q.contains(*entity));
+
+    for (enemy_entity, enemy_transform, has_mark) in &enemy_q {
+        let position = enemy_transform.translation.xy();
+
+        if position.distance(center) > potion.radius {
+            continue;
+        }
+
+        if potion.damage > 0 {
+            damage.write(DamageEvent {
+                target: enemy_entity,
+                source: hitbox.attacker,
+                amount: potion.damage,
+                damage_type: potion.damage_type,
+                kind: DamageKind::Hit,
+                position,
+            });
+        }
+
+        if let Some(kind) = potion.mark
+            && !has_mark
+        {
+            mark_enemy(&mut commands, enemy_entity, kind, &registry);
+        }
+    }
+
+    if potion.mark.is_none()
+        && let Some(hit) = hit
+        && enemy_q.get(hit).is_ok_and(|(_, _, has_mark)| has_mark)
+    {
+        commands.entity(hit).trigger(TriggerMark {
+            release: potion.release,
+            source: hitbox.attacker,
+        });
+        commands.spawn(SamplePlayer::new(audio_assets.mark_triggered.clone_weak()));
+    }
+
+    if let Some(hazard) = potion.hazard {
+        spawn_hazard(
+            &mut commands,
+            hazard,
+            center,
+            potion.mark,
+            potion.release,
+            hitbox.attacker,
+            &effect_assets,
+        );
+    }
+
+    commands.spawn((
+        ParticleSpawner::default(),
+        ParticleEffectHandle(effect_assets.potion_shatter.clone_weak()),
+        OneShot::Despawn,
+        Transform::from_translation(center.extend(ZLayer::Effects.z_layer())),
+    ));
+
+    commands.entity(trigger.target()).despawn();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brew_adds_up_potency_and_radius() {
+        let potion = brew(&[Reagent::Bile, Reagent::Frostleaf]);
+
+        assert_eq!(potion.damage, 7);
+        assert_eq!(potion.radius, BASE_RADIUS + 30.);
+    }
+
+    #[test]
+    fn brew_picks_the_strongest_element() {
+        let potion = brew(&[Reagent::Moonsalt, Reagent::Moonsalt, Reagent::Frostleaf]);
+
+        assert_eq!(potion.damage_type, DamageType::Arcane);
+    }
+
+    #[test]
+    fn brew_keeps_the_first_element_on_ties() {
+        let potion = brew(&[Reagent::Bile, Reagent::Moonsalt, Reagent::Moonsalt]);
+
+        assert_eq!(potion.damage_type, DamageType::Acid);
+    }
+
+    #[test]
+    fn brew_without_elements_is_physical() {
+        let potion = brew(&[Reagent::Pitch, Reagent::Bloodmoss]);
+
+        assert_eq!(potion.damage_type, DamageType::Physical);
+        assert_eq!(potion.hazard, Some(HazardKind::TarCloud));
+        assert_eq!(potion.mark, Some(MarkKind::Leech));
+        assert!(matches!(potion.release, Release::Detonate));
+    }
+
+    #[test]
+    fn brew_lets_the_last_reagent_win() {
+        let potion = brew(&[
+            Reagent::Bile,
+            Reagent::Lodestone,
+            Reagent::Brimstone,
+            Reagent::Quicksilver,
+        ]);
+
+        assert_eq!(potion.hazard, Some(HazardKind::FirePatch));
+        assert!(matches!(potion.release, Release::ChainLightning { .. }));
+    }
+}
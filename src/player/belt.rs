@@ -0,0 +1,130 @@
+use bevy::{color::palettes::css::LIGHT_GRAY, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+use crate::combat::Attacking;
+use crate::damage_numbers::FloatingText;
+use crate::pickup::Inventory;
+use crate::player::{
+    Player,
+    alchemy::{BrewPotion, PotionDefinition, Reagent},
+    input::{CyclePotion, RefillPotion, SelectPotion},
+};
+
+pub(super) const BELT_SLOTS: usize = 4;
+
+pub(super) const CHARGES_PER_BREW: u8 = 5;
+
+#[derive(Reflect, Clone)]
+pub(super) struct BeltSlot {
+    pub(super) potion: PotionDefinition,
+    pub(super) charges: u8,
+    pub(super) reagents: Vec<Reagent>,
+}
+
+#[derive(Component, Reflect, Default)]
+pub(super) struct PotionBelt {
+    pub(super) slots: [Option<BeltSlot>; BELT_SLOTS],
+    pub(super) active: usize,
+}
+
+impl PotionBelt {
+    pub(super) fn ready(&self) -> Option<(usize, PotionDefinition)> {
+        self.slots[self.active]
+            .as_ref()
+            .filter(|slot| slot.charges > 0)
+            .map(|slot| (self.active, slot.potion))
+    }
+}
+
+pub(super) fn select_potion(
+    trigger: Trigger<Fired<SelectPotion>>,
+    mut belt: Single<&mut PotionBelt>,
+) {
+    // The number keys are scaled to their slot, starting at one.
+    let slot = trigger.value as usize;
+
+    if (1..=BELT_SLOTS).contains(&slot) {
+        belt.active = slot - 1;
+    }
+}
+
+pub(super) fn cycle_potion(
+    trigger: Trigger<Fired<CyclePotion>>,
+    mut belt: Single<&mut PotionBelt>,
+) {
+    // Some platforms turn a vertical scroll into a horizontal one while shift is held.
+    let delta = trigger.value.x + trigger.value.y;
+
+    if delta > 0. {
+        belt.active = (belt.active + 1) % BELT_SLOTS;
+    } else if delta < 0. {
+        belt.active = (belt.active + BELT_SLOTS - 1) % BELT_SLOTS;
+    }
+}
+
+pub(super) fn consume_potion(
+    trigger: Trigger<OnInsert, Attacking>,
+    attacking_q: Query<&Attacking>,
+    mut belt_q: Query<&mut PotionBelt>,
+) {
+    let Ok(attacking) = attacking_q.get(trigger.target()) else {
+        return;
+    };
+
+    let Some(index) = attacking.potion_slot else {
+        return;
+    };
+
+    if let Ok(mut belt) = belt_q.get_mut(trigger.target())
+        && let Some(Some(slot)) = belt.slots.get_mut(index)
+    {
+        slot.charges = slot.charges.saturating_sub(1);
+    }
+}
+
+pub(super) fn refill_potion(
+    _: Trigger<Fired<RefillPotion>>,
+    mut commands: Commands,
+    player: Single<(Entity, &PotionBelt, &Transform), With<Player>>,
+    mut inventory: ResMut<Inventory>,
+    mut floating_text: EventWriter<FloatingText>,
+) {
+    let (player_entity, belt, transform) = player.into_inner();
+
+    let Some(slot) = &belt.slots[belt.active] else {
+        return;
+    };
+
+    if slot.charges == CHARGES_PER_BREW {
+        return;
+    }
+
+    let missing = slot.reagents.iter().any(|reagent| {
+        let needed = slot
+            .reagents
+            .iter()
+            .filter(|other| *other == reagent)
+            .count();
+        inventory.reagents.get(reagent).copied().unwrap_or(0) < needed as u32
+    });
+
+    if missing {
+        floating_text.write(FloatingText {
+            text: "Missing reagents".to_string(),
+            color: Color::from(LIGHT_GRAY),
+            position: transform.translation.xy() + Vec2::Y * 20.,
+        });
+        return;
+    }
+
+    for reagent in &slot.reagents {
+        if let Some(count) = inventory.reagents.get_mut(reagent) {
+            *count -= 1;
+        }
+    }
+
+    commands.entity(player_entity).trigger(BrewPotion {
+        reagents: slot.reagents.clone(),
+        slot: belt.active,
+    });
+}
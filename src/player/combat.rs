@@ -1,62 +1,115 @@
-use std::time::Duration;
+use std::{f32::consts::PI, time::Duration};
 
 use avian2d::prelude::*;
-use bevy::{prelude::*, time::Stopwatch};
+use bevy::{
+    color::palettes::css::{DODGER_BLUE, GOLD, LIGHT_GRAY},
+    prelude::*,
+    time::Stopwatch,
+};
 use bevy_enhanced_input::prelude::*;
 use bevy_enoki::{ParticleEffectHandle, ParticleSpawner, prelude::OneShot};
 use bevy_seedling::sample::SamplePlayer;
+use serde::Deserialize;
 
-use crate::Health;
-use crate::combat::{Attacking, Swing, Swings};
-use crate::player::Moving;
+use crate::combat::{
+    AttackDefinition, AttackFailed, AttackFailure, AttackRequested, DamageEvent, DamageKind,
+    Health, HitRegistry, Hitbox, Invulnerable, Parries, WieldedBy, stagger,
+};
+use crate::damage_numbers::FloatingText;
+use crate::movement::{Moving, Rooted};
 use crate::{
-    AttackMovements, AudioAssets, GameCollisionLayer, InGame, ParticleAssets, Rooted, SpriteAssets,
-    ZLayer,
-    combat::AttackMovement,
-    enemy::{Enemy, FollowedBy, Following},
+    AttackAssets, AudioAssets, GameCollisionLayer, ParticleAssets, ZLayer,
+    enemy::{Enemy, FollowedBy},
     player::{
-        LookingDirection, Player, WeaponSprite,
-        input::{MovePlayer, PrimaryAttack, SecondaryAttack},
+        AimDistance, LookingDirection, MoveInput, Player, WeaponSprite,
+        belt::PotionBelt,
+        input::{Dash, Parry, PrimaryAttack, SecondaryAttack},
+        mark::{Mark, MarkEffect, MarkKind, MarkRegistry, mark_enemy, remove_mark},
+        release::{Release, TriggerMark},
     },
 };
 
+const CHARGE_THRESHOLD: f32 = 0.2;
+
+const PARRY_INVULNERABILITY: Duration = Duration::from_millis(200);
+
 #[derive(Component, Reflect)]
-pub(super) struct Mark;
+pub(super) struct Charging {
+    attack: Handle<AttackDefinition>,
+    stopwatch: Stopwatch,
+}
 
 #[derive(Component, Reflect)]
-pub(super) struct MarkTriggered;
+pub(super) struct MarkTriggered {
+    pub(super) kind: MarkKind,
+    pub(super) source: Entity,
+}
 
 #[derive(Component, Reflect, Copy, Clone)]
-pub struct AppliesMark;
+pub struct AppliesMark(pub MarkKind);
 
 #[derive(Component, Reflect, Copy, Clone)]
-pub struct TriggersMark;
+pub struct TriggersMark(pub Release);
 
+#[derive(Deserialize, Clone, Copy)]
 pub enum AttackMarker {
-    AppliesMark,
-    TriggersMark,
+    AppliesMark(MarkKind),
+    TriggersMark(Release),
 }
 
-#[derive(Event)]
-pub struct TriggerMark;
+impl Charging {
+    fn new(attack: Handle<AttackDefinition>) -> Self {
+        Self {
+            attack,
+            stopwatch: Stopwatch::new(),
+        }
+    }
+
+    fn held(&self, attack: &Handle<AttackDefinition>) -> f32 {
+        let held = self.stopwatch.elapsed_secs();
+
+        if self.attack == *attack && held >= CHARGE_THRESHOLD {
+            held
+        } else {
+            0.
+        }
+    }
+
+    fn level(&self, attacks: &Assets<AttackDefinition>) -> f32 {
+        attacks
+            .get(&self.attack)
+            .and_then(|attack| attack.charge.as_ref())
+            .map_or(0., |charge| charge.level(self.held(&self.attack)))
+    }
+}
 
 pub(super) fn apply_mark(
     trigger: Trigger<OnCollisionStart>,
     mut commands: Commands,
-    trigger_entity: Query<Entity, With<AppliesMark>>,
-    enemy_q: Query<(Entity, &Position, &Rotation), (With<Enemy>, Without<Mark>)>,
+    applies_mark_q: Query<&AppliesMark>,
+    enemy_q: Query<(Entity, &Position, &Rotation, Option<(&Mark, &FollowedBy)>), With<Enemy>>,
     effect_assets: Res<ParticleAssets>,
+    registry: Res<MarkRegistry>,
     collisions: Collisions,
 ) {
-    let Ok((enemy_entity, position, rotation)) = enemy_q.get(trigger.collider) else {
+    let Ok((enemy_entity, position, rotation, current)) = enemy_q.get(trigger.collider) else {
         return;
     };
 
-    if !trigger_entity.contains(trigger.target()) {
+    let Ok(AppliesMark(kind)) = applies_mark_q.get(trigger.target()) else {
         return;
+    };
+
+    // The later stages of a combo put a stronger mark over the one the first stage applied.
+    if let Some((mark, followed_by)) = current {
+        if !registry.outranks(*kind, mark.kind) {
+            return;
+        }
+
+        remove_mark(&mut commands, enemy_entity, followed_by);
     }
 
-    commands.entity(enemy_entity).insert(Mark);
+    mark_enemy(&mut commands, enemy_entity, *kind, &registry);
 
     let contact_point = &collisions
         .get(trigger.target(), trigger.body.unwrap())
@@ -74,238 +127,338 @@ pub(super) fn apply_mark(
         OneShot::Despawn,
         Transform::from_translation(contact_point.extend(ZLayer::Effects.z_layer())),
     ));
-
-    commands.spawn((
-        Collider::circle(50.),
-        Sensor,
-        GameCollisionLayer::mark(),
-        Transform::from_xyz(0., 0., ZLayer::Effects.z_layer()),
-        Following::new(enemy_entity),
-        Pickable::IGNORE,
-        CollidingEntities::default(),
-        ParticleSpawner::default(),
-        ParticleEffectHandle(effect_assets.mark.clone_weak()),
-    ));
 }
 
 pub(super) fn triggers_mark_collision(
     mut commands: Commands,
-    colliding_q: Query<(Entity, &mut CollidingEntities), With<TriggersMark>>,
+    hitbox_q: Query<(&Hitbox, &TriggersMark, &HitRegistry)>,
     enemy_q: Query<Has<Mark>, With<Enemy>>,
     audio_assets: Res<AudioAssets>,
 ) {
-    for (entity, mut colliding_entites) in colliding_q {
-        if colliding_entites.is_empty() {
-            continue;
-        }
-
-        for colliding_entity in colliding_entites.drain() {
-            let Ok(has_mark) = enemy_q.get(colliding_entity) else {
+    for (hitbox, TriggersMark(release), registry) in hitbox_q {
+        for &enemy in registry.just_hit() {
+            if !enemy_q.get(enemy).unwrap_or(false) {
                 continue;
-            };
-
-            if has_mark {
-                commands.entity(colliding_entity).trigger(TriggerMark);
-                commands.entity(entity).despawn();
-                commands.spawn(SamplePlayer::new(audio_assets.mark_triggered.clone_weak()));
-
-                return;
             }
+
+            commands.entity(enemy).trigger(TriggerMark {
+                release: *release,
+                source: hitbox.attacker,
+            });
+            commands.spawn(SamplePlayer::new(audio_assets.mark_triggered.clone_weak()));
         }
-        commands.entity(entity).despawn();
     }
 }
 
+pub(super) fn attack_failed(
+    trigger: Trigger<AttackFailed>,
+    player_q: Query<&Transform, With<Player>>,
+    mut floating_text: EventWriter<FloatingText>,
+) {
+    let Ok(transform) = player_q.get(trigger.target()) else {
+        return;
+    };
+
+    let (text, color) = match trigger.reason {
+        AttackFailure::Cooldown => ("Not ready", Color::from(LIGHT_GRAY)),
+        AttackFailure::Essence => ("No essence", Color::from(DODGER_BLUE)),
+    };
+
+    floating_text.write(FloatingText {
+        text: text.to_string(),
+        color,
+        position: transform.translation.xy() + Vec2::Y * 20.,
+    });
+}
+
+pub(super) fn charge_primary_attack(
+    _: Trigger<Started<PrimaryAttack>>,
+    player: Single<Entity, With<Player>>,
+    mut commands: Commands,
+    attack_assets: Res<AttackAssets>,
+) {
+    commands
+        .entity(*player)
+        .insert(Charging::new(attack_assets.staff_slam.clone_weak()));
+}
+
 pub(super) fn primary_attack(
     _: Trigger<Fired<PrimaryAttack>>,
-    player: Single<(Entity, &Transform, &LookingDirection), (With<Player>, Without<Attacking>)>,
-    player_weapon: Single<(Entity, &Transform), With<WeaponSprite>>,
+    player: Single<(Entity, &LookingDirection, Option<&Charging>), With<Player>>,
     mut commands: Commands,
-    audio_assets: Res<AudioAssets>,
+    attack_assets: Res<AttackAssets>,
 ) {
-    let (player_entity, player_transform, direction_vector) = player.into_inner();
-    let player_pos = player_transform.translation.xy();
-    let normalized_direction_vector = direction_vector.normalize_or_zero();
-    let rooted_duration = Duration::from_secs_f32(0.8);
-
-    let mut binding = commands.entity(player_entity);
-    let entity_commands = binding.remove::<Moving>().insert((
-        Attacking {
-            swing_sound: Some((
-                Duration::from_secs_f32(0.1),
-                audio_assets.staff_swing.clone_weak(),
-            )),
-            target: normalized_direction_vector,
-            hitbox_movement: Vec::new(),
-            spawn_hitbox: vec![Duration::from_secs_f32(0.25)],
-            stopwatch: Stopwatch::new(),
-            range: 20.,
-            hitbox: vec![Collider::rectangle(4., 18.)],
-            hitbox_duration: vec![Duration::from_secs_f32(0.1)],
-            marker: Some(AttackMarker::AppliesMark),
-            sprite: None,
-            hitbox_sound: vec![audio_assets.staff_impact.clone_weak()],
-            swings: None,
-        },
-        Rooted {
-            duration: rooted_duration,
-            stopwatch: Stopwatch::new(),
-        },
-    ));
+    let (player_entity, direction_vector, charging) = player.into_inner();
 
-    entity_commands.insert(AttackMovements {
-        movements: vec![
-            (
-                Duration::from_secs_f32(0.28),
-                AttackMovement {
-                    easing: EaseFunction::QuarticOut,
-                    speed: 600.,
-                    from_to: (-normalized_direction_vector, Vec2::ZERO),
-                    duration: Duration::from_secs_f32(1.2),
-                },
-            ),
-            (
-                Duration::ZERO,
-                AttackMovement {
-                    easing: EaseFunction::QuarticOut,
-                    speed: 500.,
-                    from_to: (normalized_direction_vector, Vec2::ZERO),
-                    duration: Duration::from_secs_f32(0.27),
-                },
-            ),
-        ],
-        stopwatch: Stopwatch::new(),
-    });
+    let held = charging.map_or(0., |charging| charging.held(&attack_assets.staff_slam));
+    commands.entity(player_entity).remove::<Charging>();
 
-    let mut transform = Transform::from_translation(
-        (player_pos + normalized_direction_vector * 40.).extend(ZLayer::PlayerWeapon.z_layer()),
-    );
+    let attack = if held > 0. {
+        &attack_assets.staff_slam
+    } else {
+        &attack_assets.staff_thrust
+    };
 
-    transform.rotation = Quat::from_rotation_arc(Vec3::Y, normalized_direction_vector.extend(0.));
-
-    commands.entity(player_weapon.0).insert(Swings {
-        swings: vec![(
-            Duration::ZERO,
-            Swing {
-                from: *player_weapon.1,
-                to: transform,
-                duration: Duration::from_secs_f32(0.25),
-                easing: EaseFunction::BackOut,
-            },
-        )],
-        stopwatch: Stopwatch::new(),
+    commands.trigger(AttackRequested {
+        attacker: player_entity,
+        attack: attack.clone_weak(),
+        charge: held,
+        direction: direction_vector.0,
+        potion: None,
+        potion_slot: None,
+        target: None,
     });
 }
 
-pub(super) fn secondary_attack(
-    _: Trigger<Fired<SecondaryAttack>>,
-    player: Single<
-        (Entity, &Actions<InGame>, &LookingDirection),
-        (With<Player>, Without<Attacking>),
-    >,
+pub(super) fn dash(
+    _: Trigger<Fired<Dash>>,
+    player: Single<(Entity, &MoveInput, &LookingDirection), With<Player>>,
     mut commands: Commands,
-    sprite_assets: Res<SpriteAssets>,
+    attack_assets: Res<AttackAssets>,
 ) {
-    let (player_entity, current_movement, direction_vector) = player.into_inner();
-
-    let normalized_direction_vector = direction_vector.normalize_or_zero();
-
-    let axis2d = current_movement.value::<MovePlayer>().unwrap().as_axis2d();
-    let rooted_duration = Duration::from_secs_f32(0.25);
-    commands.entity(player_entity).remove::<Moving>().insert((
-        Attacking {
-            swing_sound: None,
-            target: normalized_direction_vector,
-            hitbox_movement: vec![(
-                LinearVelocity(normalized_direction_vector * 80.),
-                AngularVelocity(15.),
-            )],
-            spawn_hitbox: vec![Duration::from_secs_f32(0.25)],
-            stopwatch: Stopwatch::new(),
-            range: 10.,
-            hitbox: vec![Collider::circle(3.5)],
-            hitbox_duration: vec![Duration::from_secs_f32(10.)],
-            marker: Some(AttackMarker::TriggersMark),
-            sprite: Some(Sprite {
-                image: sprite_assets.potion.clone_weak(),
-                custom_size: Some(Vec2::new(7., 7.)),
-                ..default()
-            }),
-            hitbox_sound: Vec::new(),
-            swings: None,
-        },
-        AttackMovements {
-            movements: vec![(
-                Duration::ZERO,
-                AttackMovement {
-                    easing: EaseFunction::QuarticOut,
-                    speed: 50.,
-                    from_to: (axis2d, Vec2::ZERO),
-                    duration: rooted_duration,
-                },
-            )],
-            stopwatch: Stopwatch::new(),
-        },
-        Rooted {
-            duration: rooted_duration,
-            stopwatch: Stopwatch::new(),
-        },
-    ));
+    let (player_entity, input, looking_direction) = player.into_inner();
+
+    commands.trigger(AttackRequested {
+        attacker: player_entity,
+        attack: attack_assets.dash.clone_weak(),
+        charge: 0.,
+        direction: input.try_normalize().unwrap_or(looking_direction.0),
+        potion: None,
+        potion_slot: None,
+        target: None,
+    });
+}
+
+pub(super) fn parry(
+    _: Trigger<Fired<Parry>>,
+    player: Single<(Entity, &LookingDirection), With<Player>>,
+    mut commands: Commands,
+    attack_assets: Res<AttackAssets>,
+) {
+    let (player_entity, direction_vector) = player.into_inner();
+
+    commands.trigger(AttackRequested {
+        attacker: player_entity,
+        attack: attack_assets.parry.clone_weak(),
+        charge: 0.,
+        direction: direction_vector.0,
+        potion: None,
+        potion_slot: None,
+        target: None,
+    });
 }
 
-pub(super) fn trigger_mark(
-    trigger: Trigger<TriggerMark>,
-    mut collider_q: Query<&mut CollidingEntities, With<Sensor>>,
-    mut enemy_q: Query<(Entity, &FollowedBy), With<Mark>>,
+pub(super) fn parry_hit(
+    trigger: Trigger<OnCollisionStart>,
     mut commands: Commands,
+    parry_q: Query<(&Hitbox, &GlobalTransform, Option<&AppliesMark>), With<Parries>>,
+    mut enemy_hitbox_q: Query<
+        (
+            &mut Hitbox,
+            &mut Transform,
+            Option<&mut LinearVelocity>,
+            Option<&ChildOf>,
+        ),
+        Without<Parries>,
+    >,
+    enemy_q: Query<(&GlobalTransform, Has<Mark>), With<Enemy>>,
+    invulnerable_q: Query<&Invulnerable>,
+    effect_assets: Res<ParticleAssets>,
+    registry: Res<MarkRegistry>,
 ) {
-    let triggered_enemy = trigger.target();
-    let (enemy_entity, followed_by) = enemy_q.get_mut(triggered_enemy).unwrap();
+    let Ok((parry, parry_transform, applies_mark)) = parry_q.get(trigger.target()) else {
+        return;
+    };
+
+    let Ok((mut hitbox, mut transform, velocity, child_of)) =
+        enemy_hitbox_q.get_mut(trigger.collider)
+    else {
+        return;
+    };
 
-    let colliding_entites = collider_q
-        .get_mut(followed_by.iter().last().unwrap())
-        .unwrap();
+    let swung = child_of.is_some_and(|child_of| child_of.parent() == hitbox.attacker);
 
-    for entity in &colliding_entites.0 {
-        let Ok((enemy_entity, _)) = enemy_q.get(*entity) else {
-            continue;
-        };
+    if velocity.is_none() && !swung {
+        return;
+    }
+
+    Invulnerable::grant(
+        &mut commands.entity(parry.attacker),
+        invulnerable_q.get(parry.attacker).ok(),
+        PARRY_INVULNERABILITY,
+    );
+
+    if let Some(mut velocity) = velocity {
+        velocity.0 = -velocity.0;
+        transform.rotate_z(PI);
+        hitbox.attacker = parry.attacker;
+        commands
+            .entity(trigger.collider)
+            .insert(GameCollisionLayer::player_attack());
 
-        let mut entity_commands = commands.entity(enemy_entity);
-        entity_commands.remove::<Mark>();
-        entity_commands.insert(MarkTriggered);
+        return;
+    }
+
+    let enemy = hitbox.attacker;
+    commands.entity(trigger.collider).despawn();
+
+    let Ok((enemy_transform, has_mark)) = enemy_q.get(enemy) else {
+        return;
+    };
+
+    let position = enemy_transform.translation().xy();
+    let direction = (position - parry_transform.translation().xy())
+        .try_normalize()
+        .unwrap_or_else(|| parry_transform.up().xy());
+
+    stagger(
+        &mut commands.entity(enemy),
+        parry.hitstun,
+        direction * parry.knockback,
+    );
+
+    if let Some(AppliesMark(kind)) = applies_mark
+        && !has_mark
+    {
+        mark_enemy(&mut commands, enemy, *kind, &registry);
+
+        commands.spawn((
+            ParticleSpawner::default(),
+            ParticleEffectHandle(effect_assets.apply_mark.clone_weak()),
+            OneShot::Despawn,
+            Transform::from_translation(position.extend(ZLayer::Effects.z_layer())),
+        ));
     }
+}
 
-    let mut entity_commands = commands.entity(enemy_entity);
+pub(super) fn charge_secondary_attack(
+    _: Trigger<Started<SecondaryAttack>>,
+    player: Single<Entity, With<Player>>,
+    mut commands: Commands,
+    attack_assets: Res<AttackAssets>,
+) {
+    commands
+        .entity(*player)
+        .insert(Charging::new(attack_assets.potion_throw.clone_weak()));
+}
+
+pub(super) fn secondary_attack(
+    _: Trigger<Fired<SecondaryAttack>>,
+    player: Single<
+        (
+            Entity,
+            &Transform,
+            &LookingDirection,
+            &AimDistance,
+            &PotionBelt,
+            Option<&Charging>,
+        ),
+        With<Player>,
+    >,
+    mut commands: Commands,
+    attack_assets: Res<AttackAssets>,
+) {
+    let (player_entity, transform, direction_vector, distance, belt, charging) =
+        player.into_inner();
+
+    let held = charging.map_or(0., |charging| charging.held(&attack_assets.potion_throw));
+    commands.entity(player_entity).remove::<Charging>();
+
+    let Some((slot, potion)) = belt.ready() else {
+        return;
+    };
 
-    entity_commands.remove::<Mark>();
-    entity_commands.insert(MarkTriggered);
+    commands.trigger(AttackRequested {
+        attacker: player_entity,
+        attack: attack_assets.potion_throw.clone_weak(),
+        charge: held,
+        direction: direction_vector.0,
+        potion: Some(potion),
+        potion_slot: Some(slot),
+        target: Some(transform.translation.xy() + direction_vector.0 * distance.0),
+    });
 }
 
 pub(super) fn mark_triggered(
     mut commands: Commands,
-    triggered_q: Query<(Entity, &Transform, &FollowedBy), With<MarkTriggered>>,
+    triggered_q: Query<(Entity, &Transform, &FollowedBy, &MarkTriggered)>,
     mut colliding_entities: Query<&mut CollidingEntities>,
-    mut health_q: Query<(&mut Health, Has<Mark>), With<Enemy>>,
+    enemy_q: Query<(&Transform, Option<&Mark>), With<Enemy>>,
+    mut health_q: Query<&mut Health>,
+    mut damage: EventWriter<DamageEvent>,
     effect_assets: Res<ParticleAssets>,
+    registry: Res<MarkRegistry>,
 ) {
-    for (entity, transform, followed_by) in triggered_q {
+    for (entity, transform, followed_by, triggered) in triggered_q {
+        let Some(definition) = registry.get(triggered.kind) else {
+            commands.entity(entity).remove::<MarkTriggered>();
+            continue;
+        };
+        let effect = &definition.on_trigger;
+
         for following_entity in followed_by.iter() {
-            let mut colliding_entities = colliding_entities.get_mut(following_entity).unwrap();
+            let Ok(mut colliding_entities) = colliding_entities.get_mut(following_entity) else {
+                continue;
+            };
 
             for colliding_entity in colliding_entities.drain() {
-                let (mut health, has_mark) = health_q.get_mut(colliding_entity).unwrap();
-                health.current -= 5;
+                let Ok((colliding_transform, mark)) = enemy_q.get(colliding_entity) else {
+                    continue;
+                };
+
+                match *effect {
+                    MarkEffect::Detonate {
+                        splash,
+                        damage_type,
+                        ..
+                    } => {
+                        damage.write(DamageEvent {
+                            target: colliding_entity,
+                            source: triggered.source,
+                            amount: splash,
+                            damage_type,
+                            kind: DamageKind::MarkChain,
+                            position: colliding_transform.translation.xy(),
+                        });
+                    }
+                    MarkEffect::Root { duration, .. } => {
+                        commands
+                            .entity(colliding_entity)
+                            .remove::<Moving>()
+                            .insert(Rooted {
+                                duration,
+                                stopwatch: Stopwatch::new(),
+                            });
+                    }
+                    MarkEffect::Leech { .. } => {}
+                }
 
-                if has_mark {
+                if let Some(mark) = mark {
                     let mut entity_commands = commands.entity(colliding_entity);
                     entity_commands.remove::<Mark>();
-                    entity_commands.insert(MarkTriggered);
+                    entity_commands.insert(MarkTriggered {
+                        kind: mark.kind,
+                        source: triggered.source,
+                    });
                 }
             }
         }
-        let (mut health, _) = health_q.get_mut(entity).unwrap();
-        health.current -= 10;
+
+        if let MarkEffect::Leech { heal, .. } = *effect
+            && let Ok(mut health) = health_q.get_mut(triggered.source)
+        {
+            health.current = (health.current + heal).min(health.max);
+        }
+
+        let (amount, damage_type) = effect.damage();
+        damage.write(DamageEvent {
+            target: entity,
+            source: triggered.source,
+            amount,
+            damage_type,
+            kind: DamageKind::MarkDetonation,
+            position: transform.translation.xy(),
+        });
         commands.entity(entity).remove::<MarkTriggered>();
 
         let particle_transform = Transform::from_translation(
@@ -326,3 +479,23 @@ pub(super) fn mark_triggered(
         }
     }
 }
+
+pub(super) fn tick_charging(charging_q: Query<&mut Charging>, time: Res<Time<Virtual>>) {
+    for mut charging in charging_q {
+        charging.stopwatch.tick(time.delta());
+    }
+}
+
+pub(super) fn show_charge(
+    weapon_q: Query<(&mut Sprite, &WieldedBy), With<WeaponSprite>>,
+    charging_q: Query<&Charging>,
+    attacks: Res<Assets<AttackDefinition>>,
+) {
+    for (mut sprite, WieldedBy(wielder)) in weapon_q {
+        let level = charging_q
+            .get(*wielder)
+            .map_or(0., |charging| charging.level(&attacks));
+
+        sprite.color = Color::WHITE.mix(&Color::from(GOLD), level);
+    }
+}
@@ -0,0 +1,200 @@
+use avian2d::prelude::{Collider, CollidingEntities, Sensor};
+use bevy::prelude::*;
+use bevy_enoki::{Particle2dEffect, ParticleEffectHandle, ParticleSpawner};
+
+use crate::combat::{DamageEvent, DamageKind, DamageType};
+use crate::{
+    GameCollisionLayer, ParticleAssets, ZLayer,
+    enemy::Enemy,
+    player::{
+        mark::{Mark, MarkKind, MarkRegistry, mark_enemy},
+        release::{Release, TriggerMark},
+    },
+    status::{ApplyStatus, StatusKind},
+};
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HazardKind {
+    AcidPuddle,
+    FirePatch,
+    TarCloud,
+}
+
+#[derive(Reflect, Clone, Copy, Debug)]
+pub(super) enum HazardEffect {
+    Damage {
+        amount: i16,
+        damage_type: DamageType,
+    },
+    Status {
+        kind: StatusKind,
+        stacks: u8,
+        duration: f32,
+    },
+}
+
+struct HazardProperties {
+    radius: f32,
+    lifetime: f32,
+    interval: f32,
+    effect: HazardEffect,
+}
+
+#[derive(Component, Reflect)]
+#[require(Sensor, CollidingEntities)]
+pub(super) struct Hazard {
+    effect: HazardEffect,
+    interval: Timer,
+    lifetime: Timer,
+    mark: Option<MarkKind>,
+    release: Release,
+    source: Entity,
+}
+
+impl HazardKind {
+    fn properties(self) -> HazardProperties {
+        let (radius, lifetime, interval, effect) = match self {
+            HazardKind::AcidPuddle => (
+                20.,
+                4.,
+                0.5,
+                HazardEffect::Damage {
+                    amount: 2,
+                    damage_type: DamageType::Acid,
+                },
+            ),
+            HazardKind::FirePatch => (
+                15.,
+                3.,
+                0.25,
+                HazardEffect::Status {
+                    kind: StatusKind::Burn,
+                    stacks: 1,
+                    duration: 2.,
+                },
+            ),
+            HazardKind::TarCloud => (
+                30.,
+                5.,
+                0.5,
+                HazardEffect::Status {
+                    kind: StatusKind::Slow,
+                    stacks: 2,
+                    duration: 0.6,
+                },
+            ),
+        };
+
+        HazardProperties {
+            radius,
+            lifetime,
+            interval,
+            effect,
+        }
+    }
+
+    fn particle_effect(self, effect_assets: &ParticleAssets) -> Handle<Particle2dEffect> {
+        match self {
+            HazardKind::AcidPuddle => effect_assets.acid_puddle.clone_weak(),
+            HazardKind::FirePatch => effect_assets.fire_patch.clone_weak(),
+            HazardKind::TarCloud => effect_assets.tar_cloud.clone_weak(),
+        }
+    }
+}
+
+pub(super) fn spawn_hazard(
+    commands: &mut Commands,
+    kind: HazardKind,
+    position: Vec2,
+    mark: Option<MarkKind>,
+    release: Release,
+    source: Entity,
+    effect_assets: &ParticleAssets,
+) {
+    let properties = kind.properties();
+
+    commands.spawn((
+        Hazard {
+            effect: properties.effect,
+            interval: Timer::from_seconds(properties.interval, TimerMode::Repeating),
+            lifetime: Timer::from_seconds(properties.lifetime, TimerMode::Once),
+            mark,
+            release,
+            source,
+        },
+        Collider::circle(properties.radius),
+        GameCollisionLayer::hazard(),
+        Transform::from_translation(position.extend(ZLayer::Effects.z_layer())),
+        Pickable::IGNORE,
+        ParticleSpawner::default(),
+        ParticleEffectHandle(kind.particle_effect(effect_assets)),
+        Name::new("Hazard"),
+    ));
+}
+
+pub(super) fn tick_hazards(
+    mut commands: Commands,
+    hazard_q: Query<(Entity, &mut Hazard, &CollidingEntities)>,
+    enemy_q: Query<(&Transform, Has<Mark>), With<Enemy>>,
+    mut damage: EventWriter<DamageEvent>,
+    registry: Res<MarkRegistry>,
+    time: Res<Time<Virtual>>,
+) {
+    for (entity, mut hazard, colliding_entities) in hazard_q {
+        if hazard.lifetime.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if !hazard.interval.tick(time.delta()).just_finished() {
+            continue;
+        }
+
+        for colliding_entity in colliding_entities.iter() {
+            let Ok((transform, has_mark)) = enemy_q.get(*colliding_entity) else {
+                continue;
+            };
+
+            match hazard.effect {
+                HazardEffect::Damage {
+                    amount,
+                    damage_type,
+                } => {
+                    damage.write(DamageEvent {
+                        target: *colliding_entity,
+                        source: hazard.source,
+                        amount,
+                        damage_type,
+                        kind: DamageKind::Hazard,
+                        position: transform.translation.xy(),
+                    });
+                }
+                HazardEffect::Status {
+                    kind,
+                    stacks,
+                    duration,
+                } => {
+                    commands.entity(*colliding_entity).trigger(ApplyStatus {
+                        kind,
+                        stacks,
+                        duration,
+                        source: hazard.source,
+                    });
+                }
+            }
+
+            match (hazard.mark, has_mark) {
+                (Some(kind), false) => {
+                    mark_enemy(&mut commands, *colliding_entity, kind, &registry);
+                }
+                (None, true) => {
+                    commands.entity(*colliding_entity).trigger(TriggerMark {
+                        release: hazard.release,
+                        source: hazard.source,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -2,9 +2,14 @@ use bevy::prelude::*;
 
 use bevy_cursor::CursorLocation;
 use bevy_enhanced_input::prelude::*;
-use virtual_joystick::VirtualJoystickEvent;
+use virtual_joystick::{VirtualJoystickEvent, VirtualJoystickEventType};
 
-use crate::{InGame, player::LookingDirection};
+use crate::{
+    InGame,
+    player::{AimDistance, LookingDirection},
+};
+
+const JOYSTICK_AIM_RANGE: f32 = 120.;
 
 #[derive(Debug, InputAction)]
 #[input_action(output = bool)]
@@ -18,6 +23,26 @@ pub(super) struct SecondaryAttack;
 #[input_action(output = Vec2)]
 pub(crate) struct MovePlayer;
 
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+pub(crate) struct Dash;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+pub(crate) struct Parry;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = Vec2)]
+pub(crate) struct CyclePotion;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+pub(super) struct RefillPotion;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = f32)]
+pub(super) struct SelectPotion;
+
 #[derive(Default, Debug, Reflect, Hash, Clone, PartialEq, Eq)]
 pub(crate) enum JoystickID {
     Button1,
@@ -40,7 +65,7 @@ pub(super) fn binding(trigger: Trigger<Binding<InGame>>, mut players: Query<&mut
             button: MouseButton::Left,
             mod_keys: ModKeys::empty(),
         })
-        .with_conditions(Press::default());
+        .with_conditions(Release::default());
 
     actions
         .bind::<SecondaryAttack>()
@@ -48,23 +73,59 @@ pub(super) fn binding(trigger: Trigger<Binding<InGame>>, mut players: Query<&mut
             button: MouseButton::Right,
             mod_keys: ModKeys::empty(),
         })
+        .with_conditions(Release::default());
+
+    actions
+        .bind::<Dash>()
+        .to(KeyCode::Space)
+        .with_conditions(Press::default());
+
+    actions
+        .bind::<Parry>()
+        .to(KeyCode::KeyQ)
+        .with_conditions(Press::default());
+
+    // The plain mouse wheel zooms the camera.
+    actions.bind::<CyclePotion>().to(Input::MouseWheel {
+        mod_keys: ModKeys::SHIFT,
+    });
+
+    actions
+        .bind::<RefillPotion>()
+        .to(KeyCode::KeyE)
+        .with_conditions(Press::default());
+
+    actions
+        .bind::<SelectPotion>()
+        .to((
+            KeyCode::Digit1,
+            KeyCode::Digit2.with_modifiers(Scale::splat(2.)),
+            KeyCode::Digit3.with_modifiers(Scale::splat(3.)),
+            KeyCode::Digit4.with_modifiers(Scale::splat(4.)),
+        ))
         .with_conditions(Press::default());
 }
 
 pub(super) fn update_looking_direction(
     cursor: Res<CursorLocation>,
-    mut player: Single<(&mut LookingDirection, &Transform)>,
+    player: Single<(&mut LookingDirection, &mut AimDistance, &Transform)>,
 ) {
+    let (mut direction, mut distance, transform) = player.into_inner();
+
     if let Some(cursor_pos) = cursor.world_position() {
-        player.0.0 = (cursor_pos - player.1.translation.xy()).normalize();
+        let offset = cursor_pos - transform.translation.xy();
+        direction.0 = offset.normalize();
+        distance.0 = offset.length();
     }
 }
 
 pub(super) fn update_joystick(
     mut commands: Commands,
     mut joystick: EventReader<VirtualJoystickEvent<JoystickID>>,
-    mut player: Single<&mut LookingDirection>,
+    player: Single<(&mut LookingDirection, &mut AimDistance)>,
 ) {
+    let (mut direction, mut distance) = player.into_inner();
+
     for joystick_events in joystick.read() {
         match joystick_events.id() {
             JoystickID::Movement => commands.trigger(Fired::<MovePlayer> {
@@ -75,30 +136,43 @@ pub(super) fn update_joystick(
             }),
             JoystickID::Button1 => {
                 if let Some(delta) = joystick_events.axis().try_normalize() {
-                    player.0 = delta;
+                    direction.0 = delta;
                 };
 
-                if joystick_events.get_type() == virtual_joystick::VirtualJoystickEventType::Up {
-                    commands.trigger(Fired::<PrimaryAttack> {
+                match joystick_events.get_type() {
+                    VirtualJoystickEventType::Press => commands.trigger(Started::<PrimaryAttack> {
+                        value: true,
+                        state: ActionState::Ongoing,
+                    }),
+                    VirtualJoystickEventType::Up => commands.trigger(Fired::<PrimaryAttack> {
                         value: true,
                         state: ActionState::Fired,
                         fired_secs: 0.,
                         elapsed_secs: 0.,
-                    })
+                    }),
+                    VirtualJoystickEventType::Drag => {}
                 }
             }
             JoystickID::Button2 => {
                 if let Some(delta) = joystick_events.axis().try_normalize() {
-                    player.0 = delta;
+                    direction.0 = delta;
+                    distance.0 = joystick_events.axis().length().min(1.) * JOYSTICK_AIM_RANGE;
                 };
 
-                if joystick_events.get_type() == virtual_joystick::VirtualJoystickEventType::Up {
-                    commands.trigger(Fired::<SecondaryAttack> {
+                match joystick_events.get_type() {
+                    VirtualJoystickEventType::Press => {
+                        commands.trigger(Started::<SecondaryAttack> {
+                            value: true,
+                            state: ActionState::Ongoing,
+                        })
+                    }
+                    VirtualJoystickEventType::Up => commands.trigger(Fired::<SecondaryAttack> {
                         value: true,
                         state: ActionState::Fired,
                         fired_secs: 0.,
                         elapsed_secs: 0.,
-                    })
+                    }),
+                    VirtualJoystickEventType::Drag => {}
                 }
             }
         }
@@ -0,0 +1,227 @@
+use std::time::Duration;
+
+use avian2d::prelude::{Collider, CollidingEntities, Sensor};
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_enoki::{Particle2dEffect, ParticleEffectHandle, ParticleSpawner};
+use serde::Deserialize;
+
+use crate::combat::DamageType;
+use crate::enemy::{FollowedBy, Following};
+use crate::{GameCollisionLayer, ParticleAssets, ZLayer};
+
+#[derive(Reflect, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MarkKind {
+    Arcane,
+    Frost,
+    Leech,
+    Volatile,
+}
+
+#[derive(Component, Reflect)]
+pub(super) struct Mark {
+    pub(super) kind: MarkKind,
+    pub(super) timer: Timer,
+}
+
+pub(super) enum MarkEffect {
+    Detonate {
+        damage: i16,
+        splash: i16,
+        damage_type: DamageType,
+    },
+    Root {
+        damage: i16,
+        duration: Duration,
+    },
+    Leech {
+        damage: i16,
+        heal: i16,
+    },
+}
+
+pub(super) struct MarkDefinition {
+    pub(super) effect: Handle<Particle2dEffect>,
+    pub(super) radius: f32,
+    pub(super) duration: Duration,
+    pub(super) on_trigger: MarkEffect,
+    pub(super) rank: u8,
+}
+
+#[derive(Resource)]
+pub(super) struct MarkRegistry(HashMap<MarkKind, MarkDefinition>);
+
+impl MarkEffect {
+    pub(super) fn damage(&self) -> (i16, DamageType) {
+        match *self {
+            MarkEffect::Detonate {
+                damage,
+                damage_type,
+                ..
+            } => (damage, damage_type),
+            MarkEffect::Root { damage, .. } => (damage, DamageType::Frost),
+            MarkEffect::Leech { damage, .. } => (damage, DamageType::Arcane),
+        }
+    }
+}
+
+impl MarkRegistry {
+    pub(super) fn get(&self, kind: MarkKind) -> Option<&MarkDefinition> {
+        self.0.get(&kind)
+    }
+
+    pub(super) fn outranks(&self, kind: MarkKind, current: MarkKind) -> bool {
+        let rank = |kind| self.get(kind).map_or(0, |definition| definition.rank);
+        rank(kind) > rank(current)
+    }
+}
+
+pub(super) fn register_marks(mut commands: Commands, effect_assets: Res<ParticleAssets>) {
+    let marks = HashMap::from_iter([
+        (
+            MarkKind::Arcane,
+            MarkDefinition {
+                effect: effect_assets.mark.clone_weak(),
+                radius: 50.,
+                duration: Duration::from_secs(10),
+                on_trigger: MarkEffect::Detonate {
+                    damage: 10,
+                    splash: 5,
+                    damage_type: DamageType::Arcane,
+                },
+                rank: 1,
+            },
+        ),
+        (
+            MarkKind::Frost,
+            MarkDefinition {
+                effect: effect_assets.frost_mark.clone_weak(),
+                radius: 60.,
+                duration: Duration::from_secs(8),
+                on_trigger: MarkEffect::Root {
+                    damage: 5,
+                    duration: Duration::from_millis(1500),
+                },
+                rank: 2,
+            },
+        ),
+        (
+            MarkKind::Leech,
+            MarkDefinition {
+                effect: effect_assets.leech_mark.clone_weak(),
+                radius: 40.,
+                duration: Duration::from_secs(8),
+                on_trigger: MarkEffect::Leech {
+                    damage: 10,
+                    heal: 5,
+                },
+                rank: 2,
+            },
+        ),
+        (
+            MarkKind::Volatile,
+            MarkDefinition {
+                effect: effect_assets.volatile_mark.clone_weak(),
+                radius: 70.,
+                duration: Duration::from_secs(5),
+                on_trigger: MarkEffect::Detonate {
+                    damage: 20,
+                    splash: 10,
+                    damage_type: DamageType::Fire,
+                },
+                rank: 3,
+            },
+        ),
+    ]);
+
+    commands.insert_resource(MarkRegistry(marks));
+}
+
+pub(super) fn mark_enemy(
+    commands: &mut Commands,
+    enemy: Entity,
+    kind: MarkKind,
+    registry: &MarkRegistry,
+) {
+    let Some(definition) = registry.get(kind) else {
+        return;
+    };
+
+    commands.entity(enemy).insert(Mark {
+        kind,
+        timer: Timer::new(definition.duration, TimerMode::Once),
+    });
+
+    commands.spawn((
+        Collider::circle(definition.radius),
+        Sensor,
+        GameCollisionLayer::mark(),
+        Transform::from_xyz(0., 0., ZLayer::Effects.z_layer()),
+        Following::new(enemy),
+        Pickable::IGNORE,
+        CollidingEntities::default(),
+        ParticleSpawner::default(),
+        ParticleEffectHandle(definition.effect.clone_weak()),
+    ));
+}
+
+pub(super) fn remove_mark(commands: &mut Commands, entity: Entity, followed_by: &FollowedBy) {
+    commands.entity(entity).remove::<Mark>();
+
+    for following_entity in followed_by.iter() {
+        commands.entity(following_entity).despawn();
+    }
+}
+
+pub(super) fn tick_marks(
+    mut commands: Commands,
+    mark_q: Query<(Entity, &mut Mark, &FollowedBy)>,
+    time: Res<Time<Virtual>>,
+) {
+    for (entity, mut mark, followed_by) in mark_q {
+        if mark.timer.tick(time.delta()).just_finished() {
+            remove_mark(&mut commands, entity, followed_by);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(ranks: &[(MarkKind, u8)]) -> MarkRegistry {
+        MarkRegistry(HashMap::from_iter(ranks.iter().map(|&(kind, rank)| {
+            (
+                kind,
+                MarkDefinition {
+                    effect: Handle::default(),
+                    radius: 50.,
+                    duration: Duration::from_secs(10),
+                    on_trigger: MarkEffect::Leech { damage: 1, heal: 1 },
+                    rank,
+                },
+            )
+        })))
+    }
+
+    #[test]
+    fn only_a_higher_rank_outranks() {
+        let registry = registry(&[
+            (MarkKind::Arcane, 1),
+            (MarkKind::Frost, 2),
+            (MarkKind::Leech, 2),
+        ]);
+
+        assert!(registry.outranks(MarkKind::Frost, MarkKind::Arcane));
+        assert!(!registry.outranks(MarkKind::Arcane, MarkKind::Frost));
+        assert!(!registry.outranks(MarkKind::Leech, MarkKind::Frost));
+        assert!(!registry.outranks(MarkKind::Arcane, MarkKind::Arcane));
+    }
+
+    #[test]
+    fn unregistered_marks_rank_lowest() {
+        let registry = registry(&[(MarkKind::Arcane, 1)]);
+
+        assert!(registry.outranks(MarkKind::Arcane, MarkKind::Volatile));
+        assert!(!registry.outranks(MarkKind::Volatile, MarkKind::Arcane));
+    }
+}
@@ -1,33 +1,60 @@
+mod alchemy;
+mod belt;
 mod combat;
+mod hazard;
 mod input;
+mod mark;
 mod movement;
+mod release;
+
+use std::time::Duration;
 
 use avian2d::prelude::*;
-use bevy::{color::palettes::css::RED, prelude::*, sprite::Anchor};
+use bevy::{
+    color::palettes::css::{DODGER_BLUE, RED, WHITE},
+    prelude::*,
+    sprite::Anchor,
+};
 
 use bevy_enhanced_input::prelude::*;
 
 use crate::AssetState;
+use crate::combat::{
+    AttackBuffer, AttackCooldowns, DamageModifiers, Essence, EssenceBar, InvulnerableOnHit,
+    WieldedBy, hitbox_hit,
+};
 use crate::movement::Moving;
 use crate::player::combat::mark_triggered;
+use crate::status::StatusEffects;
 use crate::{
-    CursorState, GameCollisionLayer, GameState, Health, HealthBar, InGame, SpriteAssets, ZLayer,
+    AttackAssets, CursorState, GameCollisionLayer, GameState, Health, HealthBar, InGame,
+    SpriteAssets, ZLayer, despawn_all,
     player::{
+        alchemy::{BrewPotion, brew_potion, shatter_potion},
+        belt::{PotionBelt, consume_potion, cycle_potion, refill_potion, select_potion},
         combat::{
-            apply_mark, primary_attack, secondary_attack, trigger_mark, triggers_mark_collision,
+            Charging, apply_mark, attack_failed, charge_primary_attack, charge_secondary_attack,
+            dash, parry, parry_hit, primary_attack, secondary_attack, show_charge, tick_charging,
+            triggers_mark_collision,
         },
+        hazard::{Hazard, tick_hazards},
         input::{binding, update_joystick, update_looking_direction},
-        movement::{LookingDirection, apply_velocity, stop_velocity, weapon_follow},
+        mark::{register_marks, tick_marks},
+        movement::{
+            AimDistance, LookingDirection, MoveInput, apply_velocity, stop_velocity, weapon_follow,
+        },
+        release::trigger_mark,
     },
 };
 
+pub(super) use crate::player::alchemy::{Potion, PotionDefinition, Reagent};
 pub(super) use crate::player::combat::AppliesMark;
 pub(super) use crate::player::combat::AttackMarker;
 pub(super) use crate::player::combat::TriggersMark;
-pub(super) use crate::player::input::JoystickID;
+pub(super) use crate::player::input::{CyclePotion, Dash, JoystickID, Parry};
 
 #[cfg(debug_assertions)]
-use crate::player::combat::Mark;
+use crate::player::mark::Mark;
 
 pub(super) struct PlayerPlugin;
 
@@ -36,17 +63,42 @@ impl Plugin for PlayerPlugin {
         app.add_observer(apply_velocity)
             .add_observer(stop_velocity)
             .add_observer(binding)
+            .add_observer(charge_primary_attack)
             .add_observer(primary_attack)
+            .add_observer(charge_secondary_attack)
             .add_observer(secondary_attack)
+            .add_observer(dash)
+            .add_observer(parry)
+            .add_observer(parry_hit)
             .add_observer(apply_mark)
+            .add_observer(attack_failed)
             .add_observer(trigger_mark)
-            .add_systems(OnEnter(AssetState::Loaded), startup)
+            .add_observer(brew_potion)
+            .add_observer(shatter_potion)
+            .add_observer(consume_potion)
+            .add_observer(cycle_potion)
+            .add_observer(refill_potion)
+            .add_observer(select_potion)
+            .add_systems(OnEnter(AssetState::Loaded), (startup, register_marks))
+            .add_systems(
+                OnExit(GameState::GameOver),
+                (
+                    despawn_all::<Player>,
+                    despawn_all::<WeaponSprite>,
+                    despawn_all::<Hazard>,
+                    startup,
+                ),
+            )
             .add_systems(
                 Update,
                 (
-                    triggers_mark_collision,
+                    triggers_mark_collision.after(hitbox_hit),
                     weapon_follow,
+                    (tick_charging, show_charge).chain(),
+                    update_cooldown_bar,
                     mark_triggered,
+                    tick_hazards,
+                    tick_marks,
                     update_looking_direction.run_if(in_state(CursorState::Mouse)),
                     update_joystick.run_if(in_state(CursorState::Touch)),
                 )
@@ -55,14 +107,37 @@ impl Plugin for PlayerPlugin {
 
         #[cfg(debug_assertions)]
         app.register_type::<Player>()
+            .register_type::<Charging>()
+            .register_type::<CooldownBar>()
+            .register_type::<PotionBelt>()
+            .register_type::<Potion>()
+            .register_type::<Hazard>()
             .register_type::<Mark>()
-            .register_type::<LookingDirection>();
+            .register_type::<LookingDirection>()
+            .register_type::<AimDistance>()
+            .register_type::<MoveInput>();
     }
 }
 
 #[derive(Component, Reflect)]
 #[require(
+    AttackBuffer::new(0.3),
+    AttackCooldowns,
+    DamageModifiers {
+        dealt: 1.,
+        taken: 1.,
+        critical_chance: 0.1,
+        critical_multiplier: 2.,
+    },
+    Essence {
+        current: 100.,
+        max: 100.,
+        regen: 12.,
+    },
+    PotionBelt,
+    StatusEffects,
     Health { current: 100, max: 100 },
+    InvulnerableOnHit(Duration::from_secs(1)),
     Name::new("Player"),
     RigidBody::Kinematic,
     Collider::circle(5.),
@@ -70,7 +145,9 @@ impl Plugin for PlayerPlugin {
     Actions::<InGame>,
     Transform::from_xyz(0., 0., ZLayer::Player.z_layer()),
     Moving,
-    LookingDirection
+    LookingDirection,
+    AimDistance,
+    MoveInput
 )]
 pub struct Player {
     speed: f32,
@@ -92,16 +169,38 @@ impl Player {
             },
             CollisionLayers::new(
                 GameCollisionLayer::Player,
-                [GameCollisionLayer::Enemy, GameCollisionLayer::EnemyAttack],
+                [
+                    GameCollisionLayer::Enemy,
+                    GameCollisionLayer::EnemyAttack,
+                    GameCollisionLayer::Pickup,
+                ],
             ),
-            children![(
-                Mesh2d(meshes.add(Rectangle::new(15., 2.5))),
-                MeshMaterial2d(materials.add(Color::from(RED))),
-                Transform::from_translation(Vec3::new(0., 17.5, ZLayer::HealthBar.z_layer())),
-                HealthBar,
-                Name::new("Healthbar"),
-                Visibility::Visible,
-            )],
+            children![
+                (
+                    Mesh2d(meshes.add(Rectangle::new(15., 2.5))),
+                    MeshMaterial2d(materials.add(Color::from(RED))),
+                    Transform::from_translation(Vec3::new(0., 17.5, ZLayer::HealthBar.z_layer())),
+                    HealthBar,
+                    Name::new("Healthbar"),
+                    Visibility::Visible,
+                ),
+                (
+                    Mesh2d(meshes.add(Rectangle::new(15., 1.5))),
+                    MeshMaterial2d(materials.add(Color::from(DODGER_BLUE))),
+                    Transform::from_translation(Vec3::new(0., 15.5, ZLayer::HealthBar.z_layer())),
+                    EssenceBar,
+                    Name::new("Essencebar"),
+                    Visibility::Visible,
+                ),
+                (
+                    Mesh2d(meshes.add(Rectangle::new(15., 1.))),
+                    MeshMaterial2d(materials.add(Color::from(WHITE))),
+                    Transform::from_translation(Vec3::new(0., 14., ZLayer::HealthBar.z_layer())),
+                    CooldownBar,
+                    Name::new("Cooldownbar"),
+                    Visibility::Hidden,
+                )
+            ],
         )
     }
 }
@@ -109,19 +208,60 @@ impl Player {
 #[derive(Component, Reflect)]
 struct WeaponSprite;
 
+#[derive(Component, Reflect)]
+struct CooldownBar;
+
+fn update_cooldown_bar(
+    player: Single<&AttackCooldowns, With<Player>>,
+    bar: Single<(&mut Visibility, &mut Transform), With<CooldownBar>>,
+    attack_assets: Res<AttackAssets>,
+) {
+    const WIDTH: f32 = 15.;
+
+    let (mut visibility, mut transform) = bar.into_inner();
+    let percentage = player.fraction_remaining(attack_assets.potion_throw.id());
+
+    if percentage <= 0. {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    *visibility = Visibility::Visible;
+    transform.scale.x = percentage;
+    transform.translation.x = -WIDTH / 2. * (1. - percentage);
+}
+
 fn startup(
     mut commands: Commands,
     meshes: ResMut<'_, Assets<Mesh>>,
     materials: ResMut<'_, Assets<ColorMaterial>>,
     sprite_assets: Res<SpriteAssets>,
 ) {
+    let staff = sprite_assets.staff.clone();
+    let player = commands
+        .spawn((Player::bundle(50., sprite_assets, meshes, materials),))
+        .id();
+
+    let starting_potions = [
+        vec![Reagent::Bile],
+        vec![Reagent::Moonsalt, Reagent::Quicksilver],
+        vec![Reagent::Frostleaf, Reagent::Bloodmoss],
+        vec![Reagent::Brimstone, Reagent::Lodestone],
+    ];
+
+    for (slot, reagents) in starting_potions.into_iter().enumerate() {
+        commands
+            .entity(player)
+            .trigger(BrewPotion { reagents, slot });
+    }
+
     commands.spawn((
         Sprite {
-            image: sprite_assets.staff.clone(),
+            image: staff,
             ..default()
         },
         Transform::from_xyz(0., 0., ZLayer::PlayerWeapon.z_layer()),
         WeaponSprite,
+        WieldedBy(player),
     ));
-    commands.spawn((Player::bundle(50., sprite_assets, meshes, materials),));
 }
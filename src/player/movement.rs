@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use bevy_enhanced_input::prelude::*;
 
 use crate::movement::Moving;
+use crate::status::StatusEffects;
 use crate::{
     ZLayer,
     combat::Swings,
@@ -13,18 +14,46 @@ use crate::{
 #[derive(Component, Reflect, Default, Deref, DerefMut)]
 pub(super) struct LookingDirection(pub(super) Vec2);
 
+#[derive(Component, Reflect, Default, Deref, DerefMut)]
+pub(super) struct MoveInput(pub(super) Vec2);
+
+#[derive(Component, Reflect, Deref, DerefMut)]
+pub(super) struct AimDistance(pub(super) f32);
+
+impl Default for AimDistance {
+    fn default() -> Self {
+        Self(60.)
+    }
+}
+
 pub(super) fn apply_velocity(
     trigger: Trigger<Fired<MovePlayer>>,
-    mut player: Single<(&mut LinearVelocity, &Player), With<Moving>>,
+    player: Single<(
+        &mut LinearVelocity,
+        &mut MoveInput,
+        &Player,
+        &StatusEffects,
+        Has<Moving>,
+    )>,
 ) {
-    player.0.0 = trigger.value * player.1.speed;
+    let (mut velocity, mut input, player, statuses, moving) = player.into_inner();
+    input.0 = trigger.value;
+
+    if moving {
+        velocity.0 = trigger.value * player.speed * statuses.speed_multiplier();
+    }
 }
 
 pub(super) fn stop_velocity(
     trigger: Trigger<Completed<MovePlayer>>,
-    mut player: Single<&mut LinearVelocity, (With<Player>, With<Moving>)>,
+    player: Single<(&mut LinearVelocity, &mut MoveInput, Has<Moving>), With<Player>>,
 ) {
-    player.0 = trigger.value;
+    let (mut velocity, mut input, moving) = player.into_inner();
+    input.0 = trigger.value;
+
+    if moving {
+        velocity.0 = trigger.value;
+    }
 }
 
 pub(super) fn weapon_follow(
@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use avian2d::prelude::{CollidingEntities, Sensor};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::combat::{DamageEvent, DamageKind, DamageType, Empowered, stagger};
+use crate::enemy::FollowedBy;
+use crate::player::{
+    combat::MarkTriggered,
+    mark::{Mark, remove_mark},
+};
+
+#[derive(Reflect, Deserialize, Clone, Copy, Debug)]
+pub enum Release {
+    Detonate,
+    ChainLightning { damage: i16, hops: u8, range: f32 },
+    Implosion { pull: f32, stun: f32 },
+    Consume { per_mark: f32, duration: f32 },
+}
+
+#[derive(Event)]
+pub struct TriggerMark {
+    pub(super) release: Release,
+    pub(super) source: Entity,
+}
+
+fn set_off(commands: &mut Commands, entity: Entity, mark: &Mark, source: Entity) {
+    commands
+        .entity(entity)
+        .remove::<Mark>()
+        .insert(MarkTriggered {
+            kind: mark.kind,
+            source,
+        });
+}
+
+pub(super) fn trigger_mark(
+    trigger: Trigger<TriggerMark>,
+    collider_q: Query<&CollidingEntities, With<Sensor>>,
+    enemy_q: Query<(Entity, &Transform, &FollowedBy, &Mark)>,
+    transform_q: Query<&Transform>,
+    mut damage: EventWriter<DamageEvent>,
+    mut commands: Commands,
+) {
+    let source = trigger.source;
+
+    let Ok((enemy_entity, transform, followed_by, mark)) = enemy_q.get(trigger.target()) else {
+        return;
+    };
+
+    let Some(Ok(colliding_entities)) = followed_by
+        .iter()
+        .last()
+        .map(|sensor| collider_q.get(sensor))
+    else {
+        return;
+    };
+
+    let center = transform.translation.xy();
+
+    match trigger.release {
+        Release::Detonate => {
+            for (neighbour, _, _, neighbour_mark) in enemy_q.iter_many(colliding_entities.iter()) {
+                set_off(&mut commands, neighbour, neighbour_mark, source);
+            }
+
+            set_off(&mut commands, enemy_entity, mark, source);
+        }
+        Release::ChainLightning {
+            damage: amount,
+            hops,
+            range,
+        } => {
+            set_off(&mut commands, enemy_entity, mark, source);
+
+            let mut struck = vec![enemy_entity];
+            let mut position = center;
+
+            for _ in 0..hops {
+                let Some((next, next_transform, _, next_mark)) = enemy_q
+                    .iter()
+                    .filter(|(entity, transform, ..)| {
+                        !struck.contains(entity)
+                            && transform.translation.xy().distance(position) <= range
+                    })
+                    .min_by(|(_, a, ..), (_, b, ..)| {
+                        let a = a.translation.xy().distance_squared(position);
+                        let b = b.translation.xy().distance_squared(position);
+                        a.total_cmp(&b)
+                    })
+                else {
+                    break;
+                };
+
+                position = next_transform.translation.xy();
+                struck.push(next);
+
+                damage.write(DamageEvent {
+                    target: next,
+                    source,
+                    amount,
+                    damage_type: DamageType::Arcane,
+                    kind: DamageKind::MarkChain,
+                    position,
+                });
+                set_off(&mut commands, next, next_mark, source);
+            }
+        }
+        Release::Implosion { pull, stun } => {
+            for (neighbour, neighbour_transform) in colliding_entities
+                .iter()
+                .filter(|entity| **entity != enemy_entity)
+                .filter_map(|entity| Some((*entity, transform_q.get(*entity).ok()?)))
+            {
+                let direction = (center - neighbour_transform.translation.xy()).normalize_or_zero();
+
+                stagger(
+                    &mut commands.entity(neighbour),
+                    Duration::from_secs_f32(stun),
+                    direction * pull,
+                );
+            }
+
+            set_off(&mut commands, enemy_entity, mark, source);
+        }
+        Release::Consume { per_mark, duration } => {
+            let mut consumed = 1;
+
+            for (neighbour, _, neighbour_followed_by, _) in enemy_q
+                .iter_many(colliding_entities.iter())
+                .filter(|(entity, ..)| *entity != enemy_entity)
+            {
+                remove_mark(&mut commands, neighbour, neighbour_followed_by);
+                consumed += 1;
+            }
+
+            remove_mark(&mut commands, enemy_entity, followed_by);
+
+            commands.entity(source).try_insert(Empowered {
+                multiplier: 1. + per_mark * consumed as f32,
+                timer: Timer::from_seconds(duration, TimerMode::Once),
+            });
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use bevy::{color::palettes::css::LIGHT_GRAY, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+use crate::{InGame, damage_numbers::FloatingText, player::Player};
+
+pub(super) struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Settings>()
+            .add_observer(binding)
+            .add_observer(toggle_damage_numbers)
+            .add_observer(toggle_hit_stop)
+            .add_observer(toggle_screen_shake);
+
+        #[cfg(debug_assertions)]
+        app.register_type::<Settings>();
+    }
+}
+
+#[derive(Resource, Reflect)]
+pub(super) struct Settings {
+    pub(super) damage_numbers: bool,
+    pub(super) hit_stop: bool,
+    pub(super) hit_stop_intensity: f32,
+    pub(super) screen_shake: bool,
+    pub(super) screen_shake_intensity: f32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            damage_numbers: true,
+            hit_stop: true,
+            hit_stop_intensity: 1.,
+            screen_shake: true,
+            screen_shake_intensity: 1.,
+        }
+    }
+}
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct ToggleDamageNumbers;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct ToggleHitStop;
+
+#[derive(Debug, InputAction)]
+#[input_action(output = bool)]
+struct ToggleScreenShake;
+
+fn binding(trigger: Trigger<Binding<InGame>>, mut players: Query<&mut Actions<InGame>>) {
+    let mut actions = players.get_mut(trigger.target()).unwrap();
+
+    actions
+        .bind::<ToggleDamageNumbers>()
+        .to(KeyCode::F1)
+        .with_conditions(Press::default());
+
+    actions
+        .bind::<ToggleHitStop>()
+        .to(KeyCode::F2)
+        .with_conditions(Press::default());
+
+    actions
+        .bind::<ToggleScreenShake>()
+        .to(KeyCode::F3)
+        .with_conditions(Press::default());
+}
+
+fn toggle_damage_numbers(
+    _: Trigger<Fired<ToggleDamageNumbers>>,
+    mut settings: ResMut<Settings>,
+    player: Single<&Transform, With<Player>>,
+    floating_text: EventWriter<FloatingText>,
+) {
+    settings.damage_numbers = !settings.damage_numbers;
+    announce(
+        "Damage numbers",
+        settings.damage_numbers,
+        &player,
+        floating_text,
+    );
+}
+
+fn toggle_hit_stop(
+    _: Trigger<Fired<ToggleHitStop>>,
+    mut settings: ResMut<Settings>,
+    player: Single<&Transform, With<Player>>,
+    floating_text: EventWriter<FloatingText>,
+) {
+    settings.hit_stop = !settings.hit_stop;
+    announce("Hit stop", settings.hit_stop, &player, floating_text);
+}
+
+fn toggle_screen_shake(
+    _: Trigger<Fired<ToggleScreenShake>>,
+    mut settings: ResMut<Settings>,
+    player: Single<&Transform, With<Player>>,
+    floating_text: EventWriter<FloatingText>,
+) {
+    settings.screen_shake = !settings.screen_shake;
+    announce(
+        "Screen shake",
+        settings.screen_shake,
+        &player,
+        floating_text,
+    );
+}
+
+fn announce(
+    name: &str,
+    enabled: bool,
+    player: &Transform,
+    mut floating_text: EventWriter<FloatingText>,
+) {
+    let state = if enabled { "on" } else { "off" };
+
+    floating_text.write(FloatingText {
+        text: format!("{name} {state}"),
+        color: Color::from(LIGHT_GRAY),
+        position: player.translation.xy() + Vec2::Y * 20.,
+    });
+}
@@ -0,0 +1,382 @@
+use bevy::{
+    color::palettes::css::{GOLD, GRAY, LIGHT_SKY_BLUE, LIME, ORANGE_RED, YELLOW},
+    prelude::*,
+};
+
+use crate::{
+    GameState,
+    combat::{DamageEvent, DamageKind, DamageType, stagger},
+    movement::Stunned,
+};
+
+pub(super) struct StatusPlugin;
+
+impl Plugin for StatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(apply_status)
+            .add_observer(tint_applied)
+            .add_observer(tint_expired)
+            .add_systems(
+                Update,
+                tick_status_effects.run_if(in_state(GameState::Running)),
+            );
+
+        #[cfg(debug_assertions)]
+        app.register_type::<StatusEffects>();
+    }
+}
+
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum StatusKind {
+    Burn,
+    Haste,
+    Poison,
+    Slow,
+    Stun,
+    Weaken,
+}
+
+#[derive(Clone, Copy)]
+enum StackPolicy {
+    Add,
+    Max,
+    Refresh,
+}
+
+struct StatusProperties {
+    policy: StackPolicy,
+    max_stacks: u8,
+    per_stack: f32,
+    damage_over_time: Option<(f32, DamageType)>,
+}
+
+#[derive(Reflect, Clone, Debug)]
+pub(crate) struct StatusEffect {
+    pub(crate) kind: StatusKind,
+    pub(crate) stacks: u8,
+    pub(crate) source: Entity,
+    duration: Timer,
+    interval: Option<Timer>,
+}
+
+#[derive(Component, Reflect, Default)]
+pub(crate) struct StatusEffects(Vec<StatusEffect>);
+
+#[derive(Event)]
+pub(crate) struct ApplyStatus {
+    pub(crate) kind: StatusKind,
+    pub(crate) stacks: u8,
+    pub(crate) duration: f32,
+    pub(crate) source: Entity,
+}
+
+#[derive(Event)]
+pub(crate) struct StatusApplied {
+    pub(crate) kind: StatusKind,
+    pub(crate) stacks: u8,
+}
+
+#[derive(Event)]
+pub(crate) struct StatusExpired {
+    pub(crate) kind: StatusKind,
+}
+
+impl StatusKind {
+    fn properties(self) -> StatusProperties {
+        let (policy, max_stacks, per_stack, damage_over_time) = match self {
+            StatusKind::Burn => (StackPolicy::Refresh, 1, 2., Some((0.5, DamageType::Fire))),
+            StatusKind::Haste => (StackPolicy::Max, 3, 0.2, None),
+            StatusKind::Poison => (StackPolicy::Add, 5, 1., Some((1., DamageType::Acid))),
+            StatusKind::Slow => (StackPolicy::Max, 3, 0.3, None),
+            StatusKind::Stun => (StackPolicy::Max, 1, 0., None),
+            StatusKind::Weaken => (StackPolicy::Max, 3, 0.15, None),
+        };
+
+        StatusProperties {
+            policy,
+            max_stacks,
+            per_stack,
+            damage_over_time,
+        }
+    }
+
+    fn color(self) -> Color {
+        Color::from(match self {
+            StatusKind::Burn => ORANGE_RED,
+            StatusKind::Haste => GOLD,
+            StatusKind::Poison => LIME,
+            StatusKind::Slow => LIGHT_SKY_BLUE,
+            StatusKind::Stun => YELLOW,
+            StatusKind::Weaken => GRAY,
+        })
+    }
+}
+
+impl StatusEffects {
+    pub(crate) fn stacks(&self, kind: StatusKind) -> u8 {
+        self.0
+            .iter()
+            .find(|status| status.kind == kind)
+            .map_or(0, |status| status.stacks)
+    }
+
+    // Stacks the status onto an existing one according to its policy, and returns its index.
+    fn apply(&mut self, request: &ApplyStatus) -> Option<usize> {
+        // `Timer` panics on those.
+        if !request.duration.is_finite() || request.duration <= 0. {
+            return None;
+        }
+
+        let properties = request.kind.properties();
+        let stacks = request.stacks.clamp(1, properties.max_stacks);
+        let duration = Timer::from_seconds(request.duration, TimerMode::Once);
+
+        let index = match self.0.iter().position(|status| status.kind == request.kind) {
+            Some(index) => {
+                let status = &mut self.0[index];
+
+                match properties.policy {
+                    StackPolicy::Add => {
+                        status.stacks = status
+                            .stacks
+                            .saturating_add(stacks)
+                            .min(properties.max_stacks);
+                        status.duration = duration;
+                    }
+                    StackPolicy::Max => {
+                        status.stacks = status.stacks.max(stacks);
+
+                        if status.duration.remaining_secs() < request.duration {
+                            status.duration = duration;
+                        }
+                    }
+                    StackPolicy::Refresh => status.duration = duration,
+                }
+
+                status.source = request.source;
+                index
+            }
+            None => {
+                self.0.push(StatusEffect {
+                    kind: request.kind,
+                    stacks,
+                    source: request.source,
+                    duration,
+                    interval: properties
+                        .damage_over_time
+                        .map(|(interval, _)| Timer::from_seconds(interval, TimerMode::Repeating)),
+                });
+                self.0.len() - 1
+            }
+        };
+
+        Some(index)
+    }
+
+    fn strength(&self, kind: StatusKind) -> f32 {
+        f32::from(self.stacks(kind)) * kind.properties().per_stack
+    }
+
+    pub(crate) fn speed_multiplier(&self) -> f32 {
+        (1. - self.strength(StatusKind::Slow)).max(0.) * (1. + self.strength(StatusKind::Haste))
+    }
+
+    pub(crate) fn damage_multiplier(&self) -> f32 {
+        (1. - self.strength(StatusKind::Weaken)).max(0.)
+    }
+}
+
+fn apply_status(
+    trigger: Trigger<ApplyStatus>,
+    mut commands: Commands,
+    mut status_q: Query<(&mut StatusEffects, Option<&Stunned>)>,
+) {
+    let Ok((mut statuses, stunned)) = status_q.get_mut(trigger.target()) else {
+        return;
+    };
+
+    let request = trigger.event();
+
+    let Some(index) = statuses.apply(request) else {
+        return;
+    };
+
+    let status = &statuses.0[index];
+    let remaining = status.duration.remaining();
+
+    // A stun goes through the same hitstun as a hit, so it interrupts attacks too.
+    if status.kind == StatusKind::Stun
+        && stunned.is_none_or(|stunned| {
+            stunned.duration.saturating_sub(stunned.stopwatch.elapsed()) < remaining
+        })
+    {
+        stagger(
+            &mut commands.entity(trigger.target()),
+            remaining,
+            Vec2::ZERO,
+        );
+    }
+
+    commands.entity(trigger.target()).trigger(StatusApplied {
+        kind: status.kind,
+        stacks: status.stacks,
+    });
+}
+
+fn tick_status_effects(
+    mut commands: Commands,
+    status_q: Query<(Entity, &mut StatusEffects, &Transform)>,
+    mut damage: EventWriter<DamageEvent>,
+    time: Res<Time<Virtual>>,
+) {
+    for (entity, mut statuses, transform) in status_q {
+        if statuses.0.is_empty() {
+            continue;
+        }
+
+        statuses.0.retain_mut(|status| {
+            let properties = status.kind.properties();
+
+            if let Some(interval) = &mut status.interval
+                && let Some((_, damage_type)) = properties.damage_over_time
+            {
+                let amount = (f32::from(status.stacks) * properties.per_stack).round() as i16;
+
+                for _ in 0..interval.tick(time.delta()).times_finished_this_tick() {
+                    damage.write(DamageEvent {
+                        target: entity,
+                        source: status.source,
+                        amount,
+                        damage_type,
+                        kind: DamageKind::Status,
+                        position: transform.translation.xy(),
+                    });
+                }
+            }
+
+            if status.duration.tick(time.delta()).finished() {
+                commands
+                    .entity(entity)
+                    .trigger(StatusExpired { kind: status.kind });
+                return false;
+            }
+
+            true
+        });
+    }
+}
+
+fn tint_applied(trigger: Trigger<StatusApplied>, mut sprite_q: Query<&mut Sprite>) {
+    if let Ok(mut sprite) = sprite_q.get_mut(trigger.target()) {
+        tint(&mut sprite, Some((trigger.kind, trigger.stacks)));
+    }
+}
+
+fn tint_expired(
+    trigger: Trigger<StatusExpired>,
+    mut sprite_q: Query<(&StatusEffects, &mut Sprite)>,
+) {
+    let Ok((statuses, mut sprite)) = sprite_q.get_mut(trigger.target()) else {
+        return;
+    };
+
+    // Applied again in the same frame it ran out.
+    if statuses.stacks(trigger.kind) > 0 {
+        return;
+    }
+
+    let latest = statuses.0.last().map(|status| (status.kind, status.stacks));
+    tint(&mut sprite, latest);
+}
+
+fn tint(sprite: &mut Sprite, status: Option<(StatusKind, u8)>) {
+    let color = status.map_or(Color::WHITE, |(kind, stacks)| {
+        let strength = f32::from(stacks) / f32::from(kind.properties().max_stacks);
+        Color::WHITE.mix(&kind.color(), 0.3 + 0.3 * strength)
+    });
+
+    sprite.color = color.with_alpha(sprite.color.alpha());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(kind: StatusKind, stacks: u8, duration: f32) -> ApplyStatus {
+        ApplyStatus {
+            kind,
+            stacks,
+            duration,
+            source: Entity::PLACEHOLDER,
+        }
+    }
+
+    fn remaining(statuses: &StatusEffects, kind: StatusKind) -> f32 {
+        statuses
+            .0
+            .iter()
+            .find(|status| status.kind == kind)
+            .unwrap()
+            .duration
+            .remaining_secs()
+    }
+
+    #[test]
+    fn add_policy_adds_stacks_up_to_the_limit() {
+        let mut statuses = StatusEffects::default();
+
+        statuses.apply(&request(StatusKind::Poison, 2, 3.));
+        statuses.apply(&request(StatusKind::Poison, 2, 1.));
+        assert_eq!(statuses.stacks(StatusKind::Poison), 4);
+        assert_eq!(remaining(&statuses, StatusKind::Poison), 1.);
+
+        statuses.apply(&request(StatusKind::Poison, 3, 1.));
+        assert_eq!(statuses.stacks(StatusKind::Poison), 5);
+    }
+
+    #[test]
+    fn max_policy_keeps_the_stronger_and_longer_status() {
+        let mut statuses = StatusEffects::default();
+
+        statuses.apply(&request(StatusKind::Slow, 2, 3.));
+        statuses.apply(&request(StatusKind::Slow, 1, 1.));
+        assert_eq!(statuses.stacks(StatusKind::Slow), 2);
+        assert_eq!(remaining(&statuses, StatusKind::Slow), 3.);
+
+        statuses.apply(&request(StatusKind::Slow, 3, 5.));
+        assert_eq!(statuses.stacks(StatusKind::Slow), 3);
+        assert_eq!(remaining(&statuses, StatusKind::Slow), 5.);
+    }
+
+    #[test]
+    fn refresh_policy_only_restarts_the_duration() {
+        let mut statuses = StatusEffects::default();
+
+        statuses.apply(&request(StatusKind::Burn, 1, 3.));
+        statuses.apply(&request(StatusKind::Burn, 1, 1.));
+        assert_eq!(statuses.stacks(StatusKind::Burn), 1);
+        assert_eq!(remaining(&statuses, StatusKind::Burn), 1.);
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        let mut statuses = StatusEffects::default();
+
+        assert_eq!(statuses.apply(&request(StatusKind::Slow, 1, -1.)), None);
+        assert_eq!(
+            statuses.apply(&request(StatusKind::Slow, 1, f32::NAN)),
+            None
+        );
+        assert_eq!(statuses.stacks(StatusKind::Slow), 0);
+    }
+
+    #[test]
+    fn slows_and_hastes_cancel_out() {
+        let mut statuses = StatusEffects::default();
+        assert_eq!(statuses.speed_multiplier(), 1.);
+
+        statuses.apply(&request(StatusKind::Slow, 1, 1.));
+        statuses.apply(&request(StatusKind::Haste, 1, 1.));
+        assert!((statuses.speed_multiplier() - 0.84).abs() < 1e-5);
+    }
+}
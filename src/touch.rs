@@ -1,10 +1,12 @@
 use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
 use virtual_joystick::{
     JoystickFloating, NoAction, VirtualJoystickBundle, VirtualJoystickNode,
     VirtualJoystickUIBackground, VirtualJoystickUIKnob,
 };
 
 use crate::assets::SpriteAssets;
+use crate::player::{CyclePotion, Dash, Parry};
 use crate::{CursorState, JoystickID};
 
 pub(super) fn touch_interface(mut commands: Commands, sprite_assets: Res<SpriteAssets>) {
@@ -163,4 +165,91 @@ pub(super) fn touch_interface(mut commands: Commands, sprite_assets: Res<SpriteA
                 ZIndex(0),
             ));
         });
+
+    commands
+        .spawn((
+            Button,
+            ImageNode {
+                color: Color::WHITE.with_alpha(1.0),
+                image: sprite_assets.outline.clone_weak(),
+                ..default()
+            },
+            Node {
+                width: Val::Px(100.),
+                height: Val::Px(100.),
+                position_type: PositionType::Absolute,
+                right: Val::Px(150.),
+                bottom: Val::Px(150.),
+                ..default()
+            },
+            StateScoped(CursorState::Touch),
+        ))
+        .observe(cycle_potion_button);
+
+    commands
+        .spawn((
+            Button,
+            ImageNode {
+                color: Color::WHITE.with_alpha(1.0),
+                image: sprite_assets.outline.clone_weak(),
+                ..default()
+            },
+            Node {
+                width: Val::Px(100.),
+                height: Val::Px(100.),
+                position_type: PositionType::Absolute,
+                right: Val::Px(150.),
+                bottom: Val::Px(275.),
+                ..default()
+            },
+            StateScoped(CursorState::Touch),
+        ))
+        .observe(dash_button);
+
+    commands
+        .spawn((
+            Button,
+            ImageNode {
+                color: Color::WHITE.with_alpha(1.0),
+                image: sprite_assets.outline.clone_weak(),
+                ..default()
+            },
+            Node {
+                width: Val::Px(100.),
+                height: Val::Px(100.),
+                position_type: PositionType::Absolute,
+                right: Val::Px(275.),
+                bottom: Val::Px(275.),
+                ..default()
+            },
+            StateScoped(CursorState::Touch),
+        ))
+        .observe(parry_button);
+}
+
+fn dash_button(_: Trigger<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(Fired::<Dash> {
+        value: true,
+        state: ActionState::Fired,
+        fired_secs: 0.,
+        elapsed_secs: 0.,
+    });
+}
+
+fn parry_button(_: Trigger<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(Fired::<Parry> {
+        value: true,
+        state: ActionState::Fired,
+        fired_secs: 0.,
+        elapsed_secs: 0.,
+    });
+}
+
+fn cycle_potion_button(_: Trigger<Pointer<Click>>, mut commands: Commands) {
+    commands.trigger(Fired::<CyclePotion> {
+        value: Vec2::Y,
+        state: ActionState::Fired,
+        fired_secs: 0.,
+        elapsed_secs: 0.,
+    });
 }